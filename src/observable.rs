@@ -5,7 +5,7 @@ use std::hash::*;
 use std::sync::Arc;
 pub use trivial::*;
 mod from_iter;
-pub use from_iter::{from_iter, repeat};
+pub use from_iter::{from_iter, iterate, repeat};
 
 pub mod of;
 pub use of::{of, of_fn, of_option, of_result};
@@ -19,7 +19,12 @@ pub use from_stream::from_stream;
 pub use from_stream_result::from_stream_result;
 
 pub mod interval;
-pub use interval::{interval, interval_at};
+pub use interval::{
+  interval, interval_aligned, interval_at, interval_jittered,
+};
+
+pub mod generate;
+pub use generate::generate_async;
 
 pub(crate) mod connectable_observable;
 pub use connectable_observable::ConnectableObservable;
@@ -33,6 +38,14 @@ pub use timer::{timer, timer_at};
 pub mod start;
 pub use start::start;
 
+pub mod sink;
+pub use sink::{sink, sink_threads, Sink, SinkObservable};
+
+pub use crate::ops::combine_latest::{
+  combine_latest_many, combine_latest_many_threads,
+};
+pub use crate::ops::merge_fair::{merge_fair_all, merge_fair_all_threads};
+
 use crate::prelude::*;
 
 mod subscribe_item;
@@ -40,33 +53,56 @@ pub use subscribe_item::*;
 mod defer;
 pub use defer::*;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ops::blocking::BlockingObserver;
 use crate::ops::collect::CollectOp;
-use crate::ops::combine_latest::CombineLatestOpThread;
+use crate::ops::combine_latest::{
+  CombineLatestOpThread, CombineLatestOptionsOp, CombineLatestOptionsOpThreads,
+};
 use crate::ops::complete_status::{CompleteStatus, StatusOp};
 use crate::ops::delay::{DelayOpThreads, DelaySubscriptionOp};
-use crate::ops::finalize::FinalizeOpThreads;
-use crate::ops::future::{ObservableFuture, ObservableFutureObserver};
+use crate::ops::finalize::{
+  FinalizeOpThreads, FinalizeWithOpThreads, TerminationReason,
+};
+use crate::ops::first_or_timeout::{FirstOrTimeoutOp, FirstOrTimeoutOpThreads};
+use crate::ops::future::{
+  ObservableFuture, ObservableFutureObserver, SubscribeFutureObserver,
+  SubscriptionFuture,
+};
 use crate::ops::merge::MergeOpThreads;
 use crate::ops::merge_all::MergeAllOpThreads;
+use crate::ops::merge_fair::{MergeFairOp, MergeFairOpThreads};
 use crate::ops::observe_on::ObserveOnOpThreads;
 use crate::ops::on_complete::OnCompleteOp;
 use crate::ops::on_error::OnErrorOp;
+use crate::ops::reduce_sampled::{ReduceSampledOp, ReduceSampledOpThreads};
 use crate::ops::ref_count::{ShareOp, ShareOpThreads};
-use crate::ops::sample::SampleOpThreads;
+use crate::ops::retry::{
+  RetryOp, RetryOpThreads, RetryReportingOp, WindowedRetries,
+};
+use crate::ops::sample::{HoldSampleOp, HoldSampleOpThreads, SampleOpThreads};
 use crate::ops::skip_until::SkipUntilOpThreads;
-use crate::ops::stream::{ObservableStream, ObservableStreamObserver};
-use crate::ops::take_until::TakeUntilOpThreads;
+use crate::ops::stream::{
+  BoundedObservableStream, BoundedStreamObserver, ObservableStream,
+  ObservableStreamObserver, OverflowPolicy,
+};
+use crate::ops::take_until::{TakeUntilCompleteOpThreads, TakeUntilOpThreads};
+use crate::ops::timeout_restart::{
+  TimeoutOrErr, TimeoutRestartOp, TimeoutRestartOpThreads,
+};
 use crate::ops::timestamp::TimestampOp;
 use crate::ops::with_latest_from::WithLatestFromOpThreads;
 use crate::ops::zip::ZipOpThreads;
 use crate::ops::FlatMapOpThreads;
 pub use ops::box_it::BoxIt;
 
+use crate::ops::catch_error::{CatchErrorMapOp, CatchErrorMapOpThreads};
 use crate::ops::default_if_empty::DefaultIfEmptyOp;
 use crate::ops::distinct::{DistinctKeyOp, DistinctUntilKeyChangedOp};
 use crate::ops::on_error_map::OnErrorMapOp;
 use crate::ops::pairwise::PairwiseOp;
 use crate::ops::tap::TapOp;
+use interval::{AlignedIntervalObservable, IntervalObservable};
 use ops::{
   buffer::{
     BufferOp, BufferWithCountOp, BufferWithCountOrTimerOp, BufferWithTimeOp,
@@ -75,36 +111,48 @@ use ops::{
   contains::ContainsOp,
   debounce::DebounceOp,
   delay::DelayOp,
-  distinct::DistinctOp,
-  distinct::DistinctUntilChangedOp,
+  distinct::{ChangedCountOp, DistinctUntilChangedOp},
+  distinct::{DistinctOp, DistinctTtlOp},
+  explode::ExplodeOp,
   filter::FilterOp,
   filter_map::FilterMapOp,
-  finalize::FinalizeOp,
+  finalize::{FinalizeOp, FinalizeWithOp},
+  first_all::{FirstAllOp, FirstAllOpThreads},
   group_by::GroupByOp,
   last::LastOp,
   map::MapOp,
   map_to::MapToOp,
   merge::MergeOp,
   merge_all::MergeAllOp,
+  merge_all_ordered::{MergeAllOrderedOp, MergeAllOrderedOpThreads},
+  metered::MeteredOp,
   observe_on::ObserveOnOp,
+  reduce::ReduceOp,
   sample::SampleOp,
-  scan::ScanOp,
+  scan::{ScanFromFirstOp, ScanOp, ScanResetOp, ScanResetOpThreads},
   skip::SkipOp,
   skip_last::SkipLastOp,
   skip_until::SkipUntilOp,
   skip_while::SkipWhileOp,
+  slice::SliceOp,
   start_with::StartWithOp,
   subscribe_on::SubscribeOnOP,
+  switch_all::{SwitchAllOp, SwitchAllOpThreads},
   take::TakeOp,
   take_last::TakeLastOp,
-  take_until::TakeUntilOp,
+  take_until::{TakeUntilCompleteOp, TakeUntilOp},
   take_while::TakeWhileOp,
   throttle::{ThrottleEdge, ThrottleOp},
+  throttle_latest_by::ThrottleLatestByOp,
+  window_count::WindowCountOp,
   with_latest_from::WithLatestFromOp,
   zip::ZipOp,
-  Accum, AverageOp, CountOp, FlatMapOp, MinMaxOp, ReduceOp, SumOp,
+  Accum, AverageOp, CountOp, CountWhereOp, DerivativeOp, FlatMapOp, MapAsyncOp,
+  MapAsyncOpThreads, MapAsyncOrderedOp, MapAsyncOrderedOpThreads, MinMaxOp,
+  SumOp, SwitchMapOp, SwitchMapOpThreads,
 };
-use std::ops::{Add, Mul};
+use std::convert::Infallible;
+use std::ops::{Add, Mul, Sub};
 #[cfg(test)]
 pub mod fake_timer;
 
@@ -134,6 +182,57 @@ pub trait ObservableExt<Item, Err>: Sized {
     DefaultIfEmptyOp::new(self.first(), default)
   }
 
+  /// Emits the first item emitted by the source observable, or `fallback` if
+  /// the source doesn't emit within `duration`, and then completes.
+  ///
+  /// The fallback is also emitted if the source completes without emitting.
+  /// Source errors arriving before the timeout are forwarded. The source is
+  /// unsubscribed once either path resolves: right away when the timeout
+  /// fires, or by a task of `scheduler` once the first item arrives, because
+  /// the source can't be unsubscribed while it's emitting. The timer is
+  /// cancelled as soon as the source resolves.
+  ///
+  /// # Examples
+  ///
+  /// Show the cached value if the network is slow.
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use futures::executor::LocalPool;
+  /// use std::time::Duration;
+  ///
+  /// let mut pool = LocalPool::new();
+  /// let slow_network =
+  ///   observable::timer("fresh", Duration::from_millis(50), pool.spawner());
+  /// slow_network
+  ///   .first_or_timeout(Duration::from_millis(10), "cached", pool.spawner())
+  ///   .subscribe(|v| println!("{}", v));
+  ///
+  /// pool.run();
+  /// // print log:
+  /// // cached
+  /// ```
+  #[inline]
+  fn first_or_timeout<'a, SD>(
+    self,
+    duration: Duration,
+    fallback: Item,
+    scheduler: SD,
+  ) -> FirstOrTimeoutOp<'a, Self, SD, Item> {
+    FirstOrTimeoutOp::new(self, duration, fallback, scheduler)
+  }
+
+  /// A thread safe version of `first_or_timeout`
+  #[inline]
+  fn first_or_timeout_threads<SD>(
+    self,
+    duration: Duration,
+    fallback: Item,
+    scheduler: SD,
+  ) -> FirstOrTimeoutOpThreads<Self, SD, Item> {
+    FirstOrTimeoutOpThreads::new(self, duration, fallback, scheduler)
+  }
+
   /// Emit only the last final item emitted by a source observable or a
   /// default item given.
   ///
@@ -239,6 +338,38 @@ pub trait ObservableExt<Item, Err>: Sized {
     FinalizeOpThreads::new(self, f)
   }
 
+  /// Like `finalize`, but the function receives the reason the stream
+  /// finalized: it completed, errored or was unsubscribed. It still runs only
+  /// once.
+  ///
+  /// # Example
+  /// ```
+  /// use rxrust::{ops::finalize::TerminationReason, prelude::*};
+  ///
+  /// observable::from_iter(0..3)
+  ///   .finalize_with(|reason| match reason {
+  ///     TerminationReason::Complete => println!("commit"),
+  ///     _ => println!("rollback"),
+  ///   })
+  ///   .subscribe(|v| println!("write {}", v));
+  /// ```
+  #[inline]
+  fn finalize_with<F>(self, f: F) -> FinalizeWithOp<Self, F>
+  where
+    F: FnOnce(TerminationReason),
+  {
+    FinalizeWithOp::new(self, f)
+  }
+
+  /// A threads safe version of `finalize_with`
+  #[inline]
+  fn finalize_with_threads<F>(self, f: F) -> FinalizeWithOpThreads<Self, F>
+  where
+    F: FnOnce(TerminationReason),
+  {
+    FinalizeWithOpThreads::new(self, f)
+  }
+
   /// Creates an Observable that combines all the emissions from Observables
   /// that get emitted from an Observable.
   ///
@@ -329,6 +460,200 @@ pub trait ObservableExt<Item, Err>: Sized {
     self.map(f).concat_all_threads()
   }
 
+  /// Projects each source value to an Observable, such as an async call, and
+  /// emits its first value, preserving the order of the source values.
+  ///
+  /// It's a `concat_map` whose inner Observables are expected to emit exactly
+  /// one value: only the first value of every inner Observable is taken, then
+  /// the inner Observable is unsubscribed and the next one is subscribed. One
+  /// that emits while it's subscribed is unsubscribed right away, otherwise
+  /// its observer is released at once and its subscription when the stream
+  /// receives its next event, since it can't be unsubscribed while it's
+  /// emitting. An inner Observable that completes without any value emits
+  /// nothing. Unlike [`map_async_ordered`](ObservableExt::map_async_ordered),
+  /// which emits all the values of every inner Observable.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use futures::executor::LocalPool;
+  ///
+  /// let mut pool = LocalPool::new();
+  /// let spawner = pool.spawner();
+  /// observable::from_iter([1, 2, 3])
+  ///   .map_async(move |id| {
+  ///     // a lookup that takes longer for the first ids.
+  ///     let delay = Duration::from_millis(30 - id * 10);
+  ///     observable::timer(format!("user {}", id), delay, spawner.clone())
+  ///   })
+  ///   .subscribe(|user| println!("{}", user));
+  ///
+  /// pool.run();
+  /// // print logs: user 1, user 2, user 3
+  /// ```
+  #[inline]
+  fn map_async<'a, V, Item2, F>(self, f: F) -> MapAsyncOp<'a, Self, V, F, Item>
+  where
+    F: FnMut(Item) -> V,
+    V: ObservableExt<Item2, Err>,
+  {
+    FirstAllOp::new(MapOp::new(self, f))
+  }
+
+  /// A threads safe version of `map_async`
+  #[inline]
+  fn map_async_threads<V, Item2, F>(
+    self,
+    f: F,
+  ) -> MapAsyncOpThreads<Self, V, F, Item>
+  where
+    F: FnMut(Item) -> V,
+    V: ObservableExt<Item2, Err>,
+  {
+    FirstAllOpThreads::new(MapOp::new(self, f))
+  }
+
+  /// An alias of [`map_async`](ObservableExt::map_async).
+  #[inline]
+  fn concat_map_first<'a, V, Item2, F>(
+    self,
+    f: F,
+  ) -> MapAsyncOp<'a, Self, V, F, Item>
+  where
+    F: FnMut(Item) -> V,
+    V: ObservableExt<Item2, Err>,
+  {
+    self.map_async(f)
+  }
+
+  /// An alias of [`map_async_threads`](ObservableExt::map_async_threads).
+  #[inline]
+  fn concat_map_first_threads<V, Item2, F>(
+    self,
+    f: F,
+  ) -> MapAsyncOpThreads<Self, V, F, Item>
+  where
+    F: FnMut(Item) -> V,
+    V: ObservableExt<Item2, Err>,
+  {
+    self.map_async_threads(f)
+  }
+
+  /// Projects each source value to an Observable, subscribes to at most
+  /// `concurrent` of them at the same time, and emits their values in the
+  /// order of the source values.
+  ///
+  /// The values of an inner Observable that is ahead of the earliest running
+  /// one are buffered, and emitted once all the inner Observables before it
+  /// have completed. It's the ordered version of `flat_map` with a
+  /// concurrency limit, e.g. to fetch many urls in parallel but emit the
+  /// responses in the order of the requests.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `concurrent` is zero.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use futures::executor::LocalPool;
+  ///
+  /// let mut pool = LocalPool::new();
+  /// let spawner = pool.spawner();
+  /// observable::from_iter([30, 20, 10])
+  ///   .map_async_ordered(3, move |ms| {
+  ///     observable::timer(ms, Duration::from_millis(ms), spawner.clone())
+  ///   })
+  ///   .subscribe(|v| println!("{}", v));
+  ///
+  /// pool.run();
+  /// // print logs: 30 20 10
+  /// ```
+  #[inline]
+  fn map_async_ordered<'a, V, Item2, F>(
+    self,
+    concurrent: usize,
+    f: F,
+  ) -> MapAsyncOrderedOp<'a, Self, V, F, Item>
+  where
+    F: FnMut(Item) -> V,
+    MapOp<Self, F, Item>: ObservableExt<V, Err>,
+    V: ObservableExt<Item2, Err>,
+  {
+    self.map(f).merge_all_ordered(concurrent)
+  }
+
+  /// A threads safe version of `map_async_ordered`
+  #[inline]
+  fn map_async_ordered_threads<V, Item2, F>(
+    self,
+    concurrent: usize,
+    f: F,
+  ) -> MapAsyncOrderedOpThreads<Self, V, F, Item>
+  where
+    F: FnMut(Item) -> V,
+    MapOp<Self, F, Item>: ObservableExt<V, Err>,
+    V: ObservableExt<Item2, Err>,
+  {
+    self.map(f).merge_all_ordered_threads(concurrent)
+  }
+
+  /// Projects each source value to an Observable, and only emits the values
+  /// of the most recently projected Observable.
+  ///
+  /// When a new value arrives from the source, the previous inner Observable
+  /// is unsubscribed. Only the error of the active inner Observable is
+  /// propagated, an inner Observable that was switched away from can't
+  /// terminate the stream. The result completes once the source and the
+  /// active inner Observable both complete.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use std::convert::Infallible;
+  ///
+  /// let mut values = vec![];
+  /// let mut search = Subject::<&str, Infallible>::default();
+  /// search
+  ///   .clone()
+  ///   .switch_map(|query| observable::of(query.len()))
+  ///   .subscribe(|v| values.push(v));
+  ///
+  /// search.next("rx");
+  /// search.next("rxRust");
+  /// drop(search);
+  /// assert_eq!(values, vec![2, 6]);
+  /// ```
+  #[inline]
+  fn switch_map<'a, V, Item2, F>(
+    self,
+    f: F,
+  ) -> SwitchMapOp<'a, Self, V, F, Item>
+  where
+    F: FnMut(Item) -> V,
+    MapOp<Self, F, Item>: ObservableExt<V, Err>,
+    V: ObservableExt<Item2, Err>,
+  {
+    self.map(f).switch_all()
+  }
+
+  /// A threads safe version of `switch_map`
+  #[inline]
+  fn switch_map_threads<V, Item2, F>(
+    self,
+    f: F,
+  ) -> SwitchMapOpThreads<Self, V, F, Item>
+  where
+    F: FnMut(Item) -> V,
+    MapOp<Self, F, Item>: ObservableExt<V, Err>,
+    V: ObservableExt<Item2, Err>,
+  {
+    self.map(f).switch_all_threads()
+  }
+
   /// Groups items emitted by the source Observable into Observables.
   /// Each emitted Observable emits items matching the key returned
   /// by the discriminator function.
@@ -369,6 +694,46 @@ pub trait ObservableExt<Item, Err>: Sized {
     GroupByOp::new(self, discr)
   }
 
+  /// Opens a new window every `stride` items, and emits it as an Observable
+  /// of the next `size` items of the source. A window completes as soon as
+  /// it's full, so with a `stride` less than `size` several windows are open
+  /// at the same time and overlap.
+  ///
+  /// On complete or error, the open windows complete or error too.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `size` or `stride` is zero.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// observable::from_iter(0..5)
+  ///   .window_count::<Subject<_, _>>(3, 1)
+  ///   .flat_map(|window| window.sum())
+  ///   .subscribe(|sum| println!("{}", sum));
+  ///
+  /// // Prints:
+  /// // 3
+  /// // 6
+  /// // 9
+  /// // 7
+  /// // 4
+  /// ```
+  #[inline]
+  fn window_count<Subject>(
+    self,
+    size: usize,
+    stride: usize,
+  ) -> WindowCountOp<Self, Subject>
+  where
+    Subject: Clone + Default + Observer<Item, Err>,
+  {
+    WindowCountOp::new(self, size, stride)
+  }
+
   /// Creates a new stream which calls a closure on each element and uses
   /// its return as the value.
   #[inline]
@@ -389,6 +754,237 @@ pub trait ObservableExt<Item, Err>: Sized {
     OnErrorMapOp::new(self, f)
   }
 
+  /// Handles the error of the source with `handler`, which either recovers by
+  /// returning a fallback observable to continue with (`Ok`), or rethrows a,
+  /// possibly transformed, error (`Err`). The fallback observable must emit
+  /// errors of the same type as the rethrown ones.
+  ///
+  /// # Example
+  ///
+  /// Recover from timeouts, but rethrow the other errors.
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// #[derive(Debug)]
+  /// enum FetchError {
+  ///   Timeout,
+  ///   Unauthorized,
+  /// }
+  ///
+  /// let mut values = vec![];
+  /// observable::create(|mut subscriber: Subscriber<_>| {
+  ///   subscriber.next("fresh");
+  ///   subscriber.error(FetchError::Timeout);
+  /// })
+  /// .catch_error_map(|e| match e {
+  ///   FetchError::Timeout => Ok(observable::of_result(Ok("cached"))),
+  ///   e => Err(e),
+  /// })
+  /// .on_error(|e| println!("failed: {:?}", e))
+  /// .subscribe(|v| values.push(v));
+  ///
+  /// assert_eq!(values, ["fresh", "cached"]);
+  /// ```
+  #[inline]
+  fn catch_error_map<'a, F, Fallback, OutErr>(
+    self,
+    handler: F,
+  ) -> CatchErrorMapOp<'a, Self, F, Err>
+  where
+    F: FnMut(Err) -> Result<Fallback, OutErr>,
+  {
+    CatchErrorMapOp::new(self, handler)
+  }
+
+  /// A thread safe version of `catch_error_map`
+  #[inline]
+  fn catch_error_map_threads<F, Fallback, OutErr>(
+    self,
+    handler: F,
+  ) -> CatchErrorMapOpThreads<Self, F, Err>
+  where
+    F: FnMut(Err) -> Result<Fallback, OutErr>,
+  {
+    CatchErrorMapOpThreads::new(self, handler)
+  }
+
+  /// Resubscribes to the source observable when it emits an error, at most
+  /// `count` times. The error is forwarded to the downstream once the
+  /// retries are exhausted.
+  ///
+  /// Every retry subscribes to a fresh clone of the source, so operators
+  /// before `retry` start over with their initial state, e.g. `scan`
+  /// restarts from its seed unless it's configured with
+  /// [`retain_on_resubscribe`](crate::ops::scan::ScanOp::retain_on_resubscribe).
+  /// Operators after `retry` are subscribed only once, and keep their state
+  /// across the attempts.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// let mut attempts = 0;
+  /// observable::create(|mut subscriber: Subscriber<_>| {
+  ///   subscriber.next(1);
+  ///   subscriber.error("boom");
+  /// })
+  /// .retry(2)
+  /// .on_error(|e| println!("gave up: {}", e))
+  /// .subscribe(|_| attempts += 1);
+  ///
+  /// assert_eq!(attempts, 3);
+  /// ```
+  #[inline]
+  fn retry<'a>(self, count: usize) -> RetryOp<'a, Self> {
+    RetryOp::new(self, count)
+  }
+
+  /// A thread safe version of `retry`
+  #[inline]
+  fn retry_threads(self, count: usize) -> RetryOpThreads<Self> {
+    RetryOpThreads::new(self, count)
+  }
+
+  /// Treats a source that stays silent for longer than `duration` as stalled:
+  /// unsubscribes it and subscribes it again, at most `max_restarts` times,
+  /// instead of erroring right away. Every value restarts the silence timer.
+  ///
+  /// Once the restarts are exhausted, the next silence is propagated as
+  /// `TimeoutOrErr::Timeout`. An error of the source isn't restarted, it's
+  /// propagated as `TimeoutOrErr::Error`.
+  ///
+  /// # Example
+  ///
+  /// Reconnect a feed that sometimes goes silent.
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use futures::executor::LocalPool;
+  ///
+  /// let mut pool = LocalPool::new();
+  /// let feed = Subject::<i32, &str>::default();
+  /// observable::defer(move || {
+  ///   println!("connect");
+  ///   feed.clone()
+  /// })
+  /// .timeout_restart(Duration::from_millis(10), 2, pool.spawner())
+  /// .on_error(|e| println!("{:?}", e))
+  /// .subscribe(|v| println!("{}", v));
+  ///
+  /// pool.run();
+  /// // print logs: connect, connect, connect, Timeout
+  /// ```
+  #[inline]
+  fn timeout_restart<'a, SD>(
+    self,
+    duration: Duration,
+    max_restarts: usize,
+    scheduler: SD,
+  ) -> TimeoutRestartOp<'a, Self, SD> {
+    TimeoutRestartOp::new(self, duration, max_restarts, scheduler)
+  }
+
+  /// A thread safe version of `timeout_restart`
+  #[inline]
+  fn timeout_restart_threads<SD>(
+    self,
+    duration: Duration,
+    max_restarts: usize,
+    scheduler: SD,
+  ) -> TimeoutRestartOpThreads<Self, SD> {
+    TimeoutRestartOpThreads::new(self, duration, max_restarts, scheduler)
+  }
+
+  /// Like `retry`, but allows at most `max_per_window` retries within any
+  /// sliding time window of `window`, instead of a total count. The error is
+  /// forwarded to the downstream once the budget of the window is exhausted,
+  /// so a failing dependency isn't hammered by a tight retry loop, while
+  /// sporadic failures are still retried on a long living stream. The time is
+  /// read from the clock of `scheduler`.
+  ///
+  /// # Example
+  ///
+  /// Allow 3 retries per minute.
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use futures::executor::LocalPool;
+  ///
+  /// let pool = LocalPool::new();
+  /// observable::create(|subscriber: Subscriber<_>| {
+  ///   subscriber.error("unavailable")
+  /// })
+  /// .retry_windowed(3, Duration::from_secs(60), pool.spawner())
+  /// .on_error(|e| println!("gave up: {}", e))
+  /// .subscribe(|_: i32| {});
+  /// ```
+  #[inline]
+  fn retry_windowed<'a, SD>(
+    self,
+    max_per_window: usize,
+    window: Duration,
+    scheduler: SD,
+  ) -> RetryOp<'a, Self, WindowedRetries<SD>> {
+    let budget = WindowedRetries::new(max_per_window, window, scheduler);
+    RetryOp::new(self, budget)
+  }
+
+  /// A thread safe version of `retry_windowed`
+  #[inline]
+  fn retry_windowed_threads<SD>(
+    self,
+    max_per_window: usize,
+    window: Duration,
+    scheduler: SD,
+  ) -> RetryOpThreads<Self, WindowedRetries<SD>> {
+    let budget = WindowedRetries::new(max_per_window, window, scheduler);
+    RetryOpThreads::new(self, budget)
+  }
+
+  /// Like `retry`, but once all the attempts failed, the error is wrapped in
+  /// a [`RetryExhausted`](crate::ops::retry::RetryExhausted) with the number
+  /// of attempts, handy to log a flaky dependency. Values and completion pass
+  /// through untouched.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// observable::create(|subscriber: Subscriber<_>| {
+  ///   subscriber.error("connection refused")
+  /// })
+  /// .retry_reporting(2)
+  /// .on_error(|e| println!("{}", e))
+  /// .subscribe(|_: i32| {});
+  ///
+  /// // Prints: failed after 3 attempts: connection refused
+  /// ```
+  #[inline]
+  fn retry_reporting<'a>(
+    self,
+    count: usize,
+  ) -> RetryReportingOp<RetryOp<'a, Self>> {
+    RetryReportingOp {
+      source: self.retry(count),
+      attempts: count + 1,
+    }
+  }
+
+  /// A thread safe version of `retry_reporting`
+  #[inline]
+  fn retry_reporting_threads(
+    self,
+    count: usize,
+  ) -> RetryReportingOp<RetryOpThreads<Self>> {
+    RetryReportingOp {
+      source: self.retry_threads(count),
+      attempts: count + 1,
+    }
+  }
+
   /// Maps emissions to a constant value.
   #[inline]
   fn map_to<B>(self, value: B) -> MapToOp<Self, B, Item> {
@@ -440,6 +1036,47 @@ pub trait ObservableExt<Item, Err>: Sized {
     MergeOpThreads::new(self, other)
   }
 
+  /// Combines two Observables into one like `merge`, but when both sources
+  /// have values waiting, they take turns instead of draining the one that
+  /// emits first, so a fast source can't starve a slow one. The values
+  /// arriving while the downstream is busy are buffered per source until
+  /// their turn comes, the others are emitted right away like `merge` does.
+  ///
+  /// The turns are only taken between the two merged sources, chaining
+  /// doesn't share them evenly. Use
+  /// [`observable::merge_fair_all`](crate::observable::merge_fair_all) to
+  /// take turns between more sources, its example shows the values waiting
+  /// for their turn.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use rxrust::prelude::*;
+  /// let mut values = vec![];
+  /// observable::from_iter(["log"; 2])
+  ///   .merge_fair(observable::of("metric"))
+  ///   .subscribe(|v| values.push(v));
+  ///
+  /// // nothing waits, the values are emitted as they come.
+  /// assert_eq!(values, ["log", "log", "metric"]);
+  /// ```
+  #[inline]
+  fn merge_fair<S>(self, other: S) -> MergeFairOp<Self, S>
+  where
+    S: ObservableExt<Item, Err>,
+  {
+    MergeFairOp::new(self, other)
+  }
+
+  /// A threads safe version of `merge_fair`
+  #[inline]
+  fn merge_fair_threads<S>(self, other: S) -> MergeFairOpThreads<Self, S>
+  where
+    S: ObservableExt<Item, Err>,
+  {
+    MergeFairOpThreads::new(self, other)
+  }
+
   /// Converts a higher-order Observable into a first-order Observable which
   /// concurrently delivers all values that are emitted on the inner
   /// Observables.
@@ -495,6 +1132,51 @@ pub trait ObservableExt<Item, Err>: Sized {
     MergeAllOpThreads::new(self, 1)
   }
 
+  /// Like `merge_all`, but emits the values of the inner Observables in the
+  /// order they arrived. See [`ObservableExt::map_async_ordered`].
+  #[inline]
+  fn merge_all_ordered<'a, Item2>(
+    self,
+    concurrent: usize,
+  ) -> MergeAllOrderedOp<'a, Self, Item>
+  where
+    Item: ObservableExt<Item2, Err>,
+  {
+    MergeAllOrderedOp::new(self, concurrent)
+  }
+
+  /// A threads safe version of `merge_all_ordered`
+  #[inline]
+  fn merge_all_ordered_threads<Item2>(
+    self,
+    concurrent: usize,
+  ) -> MergeAllOrderedOpThreads<Self, Item>
+  where
+    Item: ObservableExt<Item2, Err>,
+  {
+    MergeAllOrderedOpThreads::new(self, concurrent)
+  }
+
+  /// Converts a higher-order Observable into a first-order Observable by
+  /// subscribing to each inner Observable as it arrives, and unsubscribing the
+  /// previous one. See [`ObservableExt::switch_map`].
+  #[inline]
+  fn switch_all<'a, Item2>(self) -> SwitchAllOp<'a, Self, Item>
+  where
+    Item: ObservableExt<Item2, Err>,
+  {
+    SwitchAllOp::new(self)
+  }
+
+  /// A threads safe version of `switch_all`
+  #[inline]
+  fn switch_all_threads<Item2>(self) -> SwitchAllOpThreads<Self, Item>
+  where
+    Item: ObservableExt<Item2, Err>,
+  {
+    SwitchAllOpThreads::new(self)
+  }
+
   /// Emit only those items from an Observable that pass a predicate test
   /// # Example
   ///
@@ -566,7 +1248,7 @@ pub trait ObservableExt<Item, Err>: Sized {
   /// # use rxrust::prelude::*;
   ///
   /// observable::from_iter(0..10).skip(5).subscribe(|v| println!("{}", v));
-
+  ///
   /// // print logs:
   /// // 6
   /// // 7
@@ -709,6 +1391,31 @@ pub trait ObservableExt<Item, Err>: Sized {
     TakeOp::new(self, count)
   }
 
+  /// Emits only the values whose index is in `[start, end)`, and completes as
+  /// soon as the value at `end - 1` is emitted.
+  ///
+  /// It works like `skip(start).take(end - start)`, with a single counter.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `start` is greater than `end`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// # use rxrust::prelude::*;
+  /// let mut values = vec![];
+  /// observable::from_iter(0..100)
+  ///   .slice(10, 15)
+  ///   .subscribe(|v| values.push(v));
+  ///
+  /// assert_eq!(values, [10, 11, 12, 13, 14]);
+  /// ```
+  #[inline]
+  fn slice(self, start: usize, end: usize) -> SliceOp<Self> {
+    SliceOp::new(self, start, end)
+  }
+
   /// Emits the values emitted by the source Observable until a `notifier`
   /// Observable emits a value.
   ///
@@ -717,6 +1424,9 @@ pub trait ObservableExt<Item, Err>: Sized {
   /// `notifier` emits a value, the output Observable stops mirroring the source
   /// Observable and completes. If the `notifier` doesn't emit any value and
   /// completes then `take_until` will pass all values.
+  ///
+  /// To stop when the `notifier` completes rather than when it emits, use
+  /// [`ObservableExt::take_until_complete`].
   #[inline]
   fn take_until<Notify, NotifyItem, NotifyErr>(
     self,
@@ -733,6 +1443,51 @@ pub trait ObservableExt<Item, Err>: Sized {
     TakeUntilOpThreads::new(self, notifier)
   }
 
+  /// Emits the values emitted by the source Observable until a `notifier`
+  /// Observable completes.
+  ///
+  /// Unlike [`ObservableExt::take_until`], the values emitted by the
+  /// `notifier` are ignored, the output Observable completes as soon as the
+  /// `notifier` completes. It's useful to bind the lifetime of the source to
+  /// another stream that signals its end by completion.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use std::convert::Infallible;
+  ///
+  /// let mut values = vec![];
+  /// let mut source = Subject::<i32, Infallible>::default();
+  /// let session = Subject::<(), Infallible>::default();
+  /// source
+  ///   .clone()
+  ///   .take_until_complete(session.clone())
+  ///   .subscribe(|v| values.push(v));
+  ///
+  /// source.next(1);
+  /// session.complete();
+  /// source.next(2);
+  /// drop(source);
+  /// assert_eq!(values, vec![1]);
+  /// ```
+  #[inline]
+  fn take_until_complete<Notify, NotifyItem, NotifyErr>(
+    self,
+    notifier: Notify,
+  ) -> TakeUntilCompleteOp<Self, Notify, NotifyItem, NotifyErr> {
+    TakeUntilCompleteOp::new(self, notifier)
+  }
+
+  /// A thread safe version of `take_until_complete`
+  #[inline]
+  fn take_until_complete_threads<Notify, NotifyItem, NotifyErr>(
+    self,
+    notifier: Notify,
+  ) -> TakeUntilCompleteOpThreads<Self, Notify, NotifyItem, NotifyErr> {
+    TakeUntilCompleteOpThreads::new(self, notifier)
+  }
+
   /// Emits values while result of an callback is true.
   ///
   /// `take_while` returns an Observable that emits values while result of an
@@ -748,7 +1503,7 @@ pub trait ObservableExt<Item, Err>: Sized {
   /// observable::from_iter(0..10)
   ///   .take_while(|v| v < &5)
   /// .subscribe(|v| println!("{}", v));
-
+  ///
   /// // print logs:
   /// // 0
   /// // 1
@@ -777,7 +1532,7 @@ pub trait ObservableExt<Item, Err>: Sized {
   /// observable::from_iter(0..10)
   ///   .take_while_inclusive(|v| v < &4)
   /// .subscribe(|v| println!("{}", v));
-
+  ///
   /// // print logs:
   /// // 0
   /// // 1
@@ -810,7 +1565,7 @@ pub trait ObservableExt<Item, Err>: Sized {
   /// observable::from_iter(0..10)
   ///   .take_last(5)
   /// .subscribe(|v| println!("{}", v));
-
+  ///
   /// // print logs:
   /// // 5
   /// // 6
@@ -874,6 +1629,99 @@ pub trait ObservableExt<Item, Err>: Sized {
     SampleOpThreads::new(self, sampling)
   }
 
+  /// Samples the source every `period` on ticks aligned to the clock of
+  /// `scheduler`, see [`observable::interval_aligned`]. All the streams
+  /// sampled with the same period tick together rather than drifting apart,
+  /// which gives consistent snapshots across them.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use futures::executor::LocalPool;
+  ///
+  /// let mut pool = LocalPool::new();
+  /// let spawner = pool.spawner();
+  /// let period = Duration::from_millis(10);
+  /// observable::interval(Duration::from_millis(1), spawner.clone())
+  ///   .sample_aligned(period, spawner.clone())
+  ///   .take(2)
+  ///   .subscribe(|v| println!("cpu {}", v));
+  /// observable::interval(Duration::from_millis(2), spawner.clone())
+  ///   .sample_aligned(period, spawner)
+  ///   .take(2)
+  ///   .subscribe(|v| println!("memory {}", v));
+  ///
+  /// pool.run();
+  /// ```
+  #[inline]
+  fn sample_aligned<SD>(
+    self,
+    period: Duration,
+    scheduler: SD,
+  ) -> SampleOp<Self, AlignedIntervalObservable<SD>, usize> {
+    SampleOp::new(self, observable::interval_aligned(period, scheduler))
+  }
+
+  /// A threads safe version of `sample_aligned`
+  #[inline]
+  fn sample_aligned_threads<SD>(
+    self,
+    period: Duration,
+    scheduler: SD,
+  ) -> SampleOpThreads<Self, AlignedIntervalObservable<SD>, usize> {
+    SampleOpThreads::new(self, observable::interval_aligned(period, scheduler))
+  }
+
+  /// Emits the most recent value of the source every `duration`, even if the
+  /// source hasn't emitted a new value since the previous tick, like a
+  /// sample-and-hold. It's useful to drive a fixed-rate consumer from an
+  /// irregular source.
+  ///
+  /// Unlike [`ObservableExt::sample`], which emits nothing when there is no
+  /// new value, the last value is held and repeated. Nothing is emitted
+  /// before the source emits its first value.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use futures::executor::LocalPool;
+  /// use std::{cell::RefCell, convert::Infallible, rc::Rc};
+  ///
+  /// let mut pool = LocalPool::new();
+  /// let mut source = Subject::<i32, Infallible>::default();
+  /// let values = Rc::new(RefCell::new(vec![]));
+  /// let c_values = values.clone();
+  /// source
+  ///   .clone()
+  ///   .hold_sample(Duration::from_millis(5), pool.spawner())
+  ///   .take(3)
+  ///   .subscribe(move |v| c_values.borrow_mut().push(v));
+  ///
+  /// source.next(1);
+  /// pool.run();
+  /// assert_eq!(*values.borrow(), [1, 1, 1]);
+  /// ```
+  #[inline]
+  fn hold_sample<SD>(
+    self,
+    duration: Duration,
+    scheduler: SD,
+  ) -> HoldSampleOp<Self, IntervalObservable<SD>, usize, Infallible> {
+    HoldSampleOp::new(self, observable::interval(duration, scheduler))
+  }
+
+  /// A threads safe version of `hold_sample`
+  #[inline]
+  fn hold_sample_threads<SD>(
+    self,
+    duration: Duration,
+    scheduler: SD,
+  ) -> HoldSampleOpThreads<Self, IntervalObservable<SD>, usize, Infallible> {
+    HoldSampleOpThreads::new(self, observable::interval(duration, scheduler))
+  }
+
   /// The Scan operator applies a function to the first item emitted by the
   /// source observable and then emits the result of that function as its
   /// own first emission. It also feeds the result of the function back into
@@ -937,12 +1785,117 @@ pub trait ObservableExt<Item, Err>: Sized {
   fn scan<OutputItem, BinaryOp>(
     self,
     binary_op: BinaryOp,
-  ) -> ScanOp<Self, BinaryOp, OutputItem, Item>
+  ) -> ScanOp<Self, BinaryOp, OutputItem, Item>
+  where
+    BinaryOp: Fn(OutputItem, Item) -> OutputItem,
+    OutputItem: Default + Clone,
+  {
+    self.scan_initial(OutputItem::default(), binary_op)
+  }
+
+  /// Works like [`scan_initial`](ObservableExt::scan_initial), but the first
+  /// item of the source is the seed of the accumulator, like
+  /// [`Iterator::reduce`].
+  ///
+  /// The first item is emitted unchanged, and every following item is folded
+  /// into the accumulator by `binary_op`.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// let mut max = vec![];
+  /// observable::from_iter([2, 1, 5, 3])
+  ///   .scan_from_first(|acc: i32, v| acc.max(v))
+  ///   .subscribe(|v| max.push(v));
+  ///
+  /// assert_eq!(max, vec![2, 2, 5, 5]);
+  /// ```
+  #[inline]
+  fn scan_from_first<BinaryOp>(
+    self,
+    binary_op: BinaryOp,
+  ) -> ScanFromFirstOp<Self, BinaryOp>
+  where
+    BinaryOp: FnMut(Item, Item) -> Item,
+    Item: Clone,
+  {
+    ScanFromFirstOp { source: self, binary_op }
+  }
+
+  /// Works like [`scan_initial`](ObservableExt::scan_initial), but the
+  /// accumulator goes back to `seed` whenever `reset_notifier` emits, and the
+  /// seed is emitted so the downstream sees the cleared state. It's useful
+  /// for running aggregates over periods, like daily totals.
+  ///
+  /// The errors and the completion of `reset_notifier` are ignored.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use std::convert::Infallible;
+  ///
+  /// let mut totals = vec![];
+  /// let mut sales = Subject::<i32, Infallible>::default();
+  /// let mut new_day = Subject::<(), Infallible>::default();
+  /// sales
+  ///   .clone()
+  ///   .scan_reset(0, |total, v| total + v, new_day.clone())
+  ///   .subscribe(|v| totals.push(v));
+  ///
+  /// sales.next(5);
+  /// sales.next(3);
+  /// new_day.next(());
+  /// sales.next(2);
+  /// drop((sales, new_day));
+  ///
+  /// assert_eq!(totals, vec![5, 8, 0, 2]);
+  /// ```
+  #[inline]
+  fn scan_reset<OutputItem, BinaryOp, Notify, NotifyItem, NotifyErr>(
+    self,
+    seed: OutputItem,
+    binary_op: BinaryOp,
+    reset_notifier: Notify,
+  ) -> ScanResetOp<
+    Self,
+    Notify,
+    BinaryOp,
+    OutputItem,
+    Item,
+    NotifyItem,
+    NotifyErr,
+  >
+  where
+    BinaryOp: FnMut(OutputItem, Item) -> OutputItem,
+    OutputItem: Clone,
+  {
+    ScanResetOp::new(self, seed, binary_op, reset_notifier)
+  }
+
+  /// A thread safe version of `scan_reset`
+  #[inline]
+  fn scan_reset_threads<OutputItem, BinaryOp, Notify, NotifyItem, NotifyErr>(
+    self,
+    seed: OutputItem,
+    binary_op: BinaryOp,
+    reset_notifier: Notify,
+  ) -> ScanResetOpThreads<
+    Self,
+    Notify,
+    BinaryOp,
+    OutputItem,
+    Item,
+    NotifyItem,
+    NotifyErr,
+  >
   where
-    BinaryOp: Fn(OutputItem, Item) -> OutputItem,
-    OutputItem: Default + Clone,
+    BinaryOp: FnMut(OutputItem, Item) -> OutputItem,
+    OutputItem: Clone,
   {
-    self.scan_initial(OutputItem::default(), binary_op)
+    ScanResetOpThreads::new(self, seed, binary_op, reset_notifier)
   }
 
   /// Apply a function to each item emitted by an observable, sequentially,
@@ -975,12 +1928,8 @@ pub trait ObservableExt<Item, Err>: Sized {
   ) -> ReduceOp<Self, BinaryOp, OutputItem, Item>
   where
     BinaryOp: Fn(OutputItem, Item) -> OutputItem,
-    OutputItem: Clone,
   {
-    // realised as a composition of `scan`, and `last`
-    let scan = self.scan_initial(initial.clone(), binary_op);
-    let last = LastOp { source: scan, last: None };
-    DefaultIfEmptyOp::new(last, initial)
+    ReduceOp::new(self, binary_op, initial)
   }
 
   /// Works like [`reduce_initial`](Observable::reduce_initial) but starts with
@@ -997,11 +1946,77 @@ pub trait ObservableExt<Item, Err>: Sized {
   ) -> ReduceOp<Self, BinaryOp, OutputItem, Item>
   where
     BinaryOp: Fn(OutputItem, Item) -> OutputItem,
-    OutputItem: Default + Clone,
+    OutputItem: Default,
   {
     self.reduce_initial(OutputItem::default(), binary_op)
   }
 
+  /// Applies a binary operator closure to each item emitted from the source
+  /// observable like [`reduce_initial`](ObservableExt::reduce_initial), but
+  /// emits the current accumulator every time the `sampler` emits, even if it
+  /// hasn't changed since the last sample.
+  ///
+  /// The final accumulator is emitted when the source completes.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// let mut source = Subject::default();
+  /// let mut sampler = Subject::default();
+  /// source
+  ///   .clone()
+  ///   .reduce_sampled(0, |acc, v| acc + v, sampler.clone())
+  ///   .subscribe(|v| println!("{}", v));
+  ///
+  /// source.next(1);
+  /// source.next(2);
+  /// sampler.next(());
+  /// source.next(3);
+  /// source.complete();
+  ///
+  /// // print log:
+  /// // 3
+  /// // 6
+  /// ```
+  #[inline]
+  fn reduce_sampled<OutputItem, BinaryOp, Sample, SampleItem>(
+    self,
+    initial: OutputItem,
+    binary_op: BinaryOp,
+    sampler: Sample,
+  ) -> ReduceSampledOp<Self, Sample, BinaryOp, OutputItem, (Item, SampleItem)>
+  where
+    BinaryOp: FnMut(OutputItem, Item) -> OutputItem,
+    Sample: ObservableExt<SampleItem, Err>,
+    OutputItem: Clone,
+  {
+    ReduceSampledOp::new(self, sampler, binary_op, initial)
+  }
+
+  /// A threads safe version of `reduce_sampled`
+  #[inline]
+  fn reduce_sampled_threads<OutputItem, BinaryOp, Sample, SampleItem>(
+    self,
+    initial: OutputItem,
+    binary_op: BinaryOp,
+    sampler: Sample,
+  ) -> ReduceSampledOpThreads<
+    Self,
+    Sample,
+    BinaryOp,
+    OutputItem,
+    (Item, SampleItem),
+  >
+  where
+    BinaryOp: FnMut(OutputItem, Item) -> OutputItem,
+    Sample: ObservableExt<SampleItem, Err>,
+    OutputItem: Clone,
+  {
+    ReduceSampledOpThreads::new(self, sampler, binary_op, initial)
+  }
+
   /// Emits the item from the source observable that had the maximum value.
   ///
   /// Emits error when source observable emits it.
@@ -1121,6 +2136,32 @@ pub trait ObservableExt<Item, Err>: Sized {
     self.reduce(|acc, _v| acc + 1)
   }
 
+  /// Emits the number of items emitted by a source observable that satisfy
+  /// the predicate when this source completes.
+  ///
+  /// Emits zero when no item matches or the source completed as an empty
+  /// sequence.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// observable::from_iter(vec![Ok(1), Err("timeout"), Ok(3), Err("refused")])
+  ///   .count_where(|e: &Result<i32, &str>| e.is_err())
+  ///   .subscribe(|v| println!("{}", v));
+  ///
+  /// // print log:
+  /// // 2
+  /// ```
+  #[inline]
+  fn count_where<F>(self, pred: F) -> CountWhereOp<Self, F, Item>
+  where
+    F: Fn(&Item) -> bool,
+  {
+    self.filter(pred).count()
+  }
+
   /// Calculates the sum of numbers emitted by an source observable and emits
   /// this sum when source completes.
   ///
@@ -1199,6 +2240,10 @@ pub trait ObservableExt<Item, Err>: Sized {
   /// have unsubscribed it will unsubscribe from the source Observable.
   /// Because the Observable is multicasting it makes the stream `hot`.
   /// This is an alias for `publish().ref_count()`
+  ///
+  /// It's the single thread version, built on `Rc` and `RefCell` with a local
+  /// `Subject`, so neither the source nor the subscribers need to be `Send`.
+  /// Use `share_threads` to share across threads.
   #[inline]
   fn share<'a>(self) -> ShareOp<'a, Item, Err, Self> {
     ShareOp::new(self)
@@ -1211,6 +2256,9 @@ pub trait ObservableExt<Item, Err>: Sized {
 
   /// Delays the emission of items from the source Observable by a given timeout
   /// or until a given `Instant`.
+  ///
+  /// Every item is emitted `dur` after it arrived, even if the scheduler is
+  /// busy in the meantime, so the spacing between the items is preserved.
   #[inline]
   fn delay<SD>(self, dur: Duration, scheduler: SD) -> DelayOp<Self, SD> {
     DelayOp { source: self, delay: dur, scheduler }
@@ -1343,6 +2391,31 @@ pub trait ObservableExt<Item, Err>: Sized {
 
   /// Emits a value from the source Observable only after a particular time span
   /// has passed without another source emission.
+  ///
+  /// Every source emission cancels the pending one and restarts the timer, so
+  /// only the last value of a burst is emitted. That's the difference from
+  /// `throttle_time` with `ThrottleEdge::tailing()`, which emits on a fixed
+  /// window however fast the values arrive. On complete, the pending value is
+  /// emitted before completing.
+  ///
+  /// #Example
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use std::time::Duration;
+  ///
+  /// let mut local_pool = FuturesLocalSchedulerPool::new();
+  /// let mut input = Subject::default();
+  /// input
+  ///   .clone()
+  ///   .debounce(Duration::from_millis(300), local_pool.spawner())
+  ///   .subscribe(|text: &str| println!("search {}", text));
+  ///
+  /// input.next("r");
+  /// input.next("rx");
+  /// input.next("rxrust");
+  /// local_pool.run();
+  /// // Prints: search rxrust
+  /// ```
   #[inline]
   fn debounce<SD>(
     self,
@@ -1428,6 +2501,53 @@ pub trait ObservableExt<Item, Err>: Sized {
     self.throttle(Box::new(move |_| duration), edge, scheduler)
   }
 
+  /// Keeps the latest value of every key, selected by `key_selector`, and
+  /// emits them at the end of each window of `duration`, in the order their
+  /// keys first appeared in the window. A window starts with the first value
+  /// after the previous one ended.
+  ///
+  /// It's a trailing `throttle_time` for every key, useful to coalesce rapid
+  /// updates per entity. On complete, the pending values are emitted before
+  /// completing, on error they are dropped.
+  ///
+  /// # Example
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use std::time::Duration;
+  ///
+  /// let mut local_pool = FuturesLocalSchedulerPool::new();
+  /// observable::from_iter([(1, "a"), (2, "b"), (1, "c")])
+  ///   .throttle_latest_by(
+  ///     Duration::from_millis(10),
+  ///     |(id, _)| *id,
+  ///     local_pool.spawner(),
+  ///   )
+  ///   .subscribe(|v| println!("{:?}", v));
+  ///
+  /// local_pool.run();
+  /// // Prints:
+  /// // (1, "c")
+  /// // (2, "b")
+  /// ```
+  #[inline]
+  fn throttle_latest_by<F, K, SD>(
+    self,
+    duration: Duration,
+    key_selector: F,
+    scheduler: SD,
+  ) -> ThrottleLatestByOp<Self, F, SD>
+  where
+    F: FnMut(&Item) -> K,
+    K: Hash + Eq,
+  {
+    ThrottleLatestByOp {
+      source: self,
+      duration,
+      key_selector,
+      scheduler,
+    }
+  }
+
   /// Returns an Observable that emits all items emitted by the source
   /// Observable that are distinct by comparison from previous items.
   #[inline]
@@ -1435,6 +2555,37 @@ pub trait ObservableExt<Item, Err>: Sized {
     DistinctOp { source: self }
   }
 
+  /// Like `distinct`, but forgets a seen value once `ttl` elapsed since it
+  /// was emitted, so the value is allowed through again. The expiration is
+  /// scheduled on `scheduler`, which also bounds the memory of an infinite
+  /// stream to the values seen within `ttl`.
+  ///
+  /// # Example
+  ///
+  /// Dedupe alerts, but allow re-alerting after an hour.
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use futures::executor::LocalPool;
+  ///
+  /// let mut local = LocalPool::new();
+  /// observable::from_iter(["disk full", "cpu hot", "disk full"])
+  ///   .distinct_ttl(Duration::from_secs(3600), local.spawner())
+  ///   .subscribe(|alert| println!("{}", alert));
+  ///
+  /// // Prints:
+  /// // disk full
+  /// // cpu hot
+  /// ```
+  #[inline]
+  fn distinct_ttl<SD>(
+    self,
+    ttl: Duration,
+    scheduler: SD,
+  ) -> DistinctTtlOp<Self, SD> {
+    DistinctTtlOp { source: self, ttl, scheduler }
+  }
+
   /// Variant of distinct that takes a key selector.
   #[inline]
   fn distinct_key<F>(self, key: F) -> DistinctKeyOp<Self, F> {
@@ -1447,6 +2598,28 @@ pub trait ObservableExt<Item, Err>: Sized {
     DistinctUntilChangedOp { source: self }
   }
 
+  /// Like `distinct_until_changed`, but emits every change with a running
+  /// count of the changes so far, as `(count, value)`. The first value counts
+  /// as the first change.
+  ///
+  /// # Example
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// observable::from_iter([1, 1, 2, 2, 1])
+  ///   .changed_count()
+  ///   .subscribe(|(count, v)| println!("change {}: {}", count, v));
+  ///
+  /// // Prints:
+  /// // change 1: 1
+  /// // change 2: 2
+  /// // change 3: 1
+  /// ```
+  #[inline]
+  fn changed_count(self) -> ChangedCountOp<Self> {
+    ChangedCountOp { source: self }
+  }
+
   /// Variant of distinct_until_changed that takes a key selector.
   #[inline]
   fn distinct_until_key_changed<F>(
@@ -1646,12 +2819,35 @@ pub trait ObservableExt<Item, Err>: Sized {
     BufferWithCountOrTimerOp { source: self, count, time, scheduler }
   }
 
+  /// Emits the items of every `Vec` emitted by the source one by one, the
+  /// inverse of the buffer operators. Useful to re-stream batched data.
+  ///
+  /// #Example
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// observable::from_iter(0..6)
+  ///   .buffer_with_count(3)
+  ///   .explode()
+  ///   .subscribe(|v| println!("{}", v));
+  ///
+  /// // Prints: 0 1 2 3 4 5
+  /// ```
+  #[inline]
+  fn explode(self) -> ExplodeOp<Self> {
+    ExplodeOp::new(self)
+  }
+
   /// Emits item which is combining latest items from two observables.
   ///
   /// combine_latest() merges two observables into one observable
   /// by applying a binary operator on the latest item of two observable
   /// whenever each of observables produces an element.
   ///
+  /// It completes when both observables completed, or as soon as one of them
+  /// completes without emitting any item, since no item can be combined
+  /// anymore.
+  ///
   /// #Example
   /// ```
   /// use rxrust::prelude::*;
@@ -1700,6 +2896,63 @@ pub trait ObservableExt<Item, Err>: Sized {
     CombineLatestOpThread::new(self, other, binary_op)
   }
 
+  /// Combines the latest items of two observables into a pair of `Option`s,
+  /// and emits it on every item of either observable, with `None` for the
+  /// side that hasn't emitted yet.
+  ///
+  /// Unlike [`ObservableExt::combine_latest`], nothing waits for both sides,
+  /// so the downstream can start right away and handle the missing values
+  /// itself. It completes when both observables completed.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use std::convert::Infallible;
+  ///
+  /// let mut views = vec![];
+  /// let mut user = Subject::<&str, Infallible>::default();
+  /// let mut orders = Subject::<usize, Infallible>::default();
+  /// user
+  ///   .clone()
+  ///   .combine_latest_options(orders.clone())
+  ///   .subscribe(|(user, orders)| {
+  ///     views.push(format!(
+  ///       "{}: {}",
+  ///       user.unwrap_or("loading..."),
+  ///       orders.map_or("loading...".into(), |n| n.to_string())
+  ///     ))
+  ///   });
+  ///
+  /// orders.next(3);
+  /// user.next("alice");
+  /// drop((user, orders));
+  ///
+  /// assert_eq!(views, ["loading...: 3", "alice: 3"]);
+  /// ```
+  #[inline]
+  fn combine_latest_options<Other, OtherItem>(
+    self,
+    other: Other,
+  ) -> CombineLatestOptionsOp<Self, Other>
+  where
+    Other: ObservableExt<OtherItem, Err>,
+  {
+    CombineLatestOptionsOp::new(self, other)
+  }
+
+  /// A thread safe version of `combine_latest_options`
+  #[inline]
+  fn combine_latest_options_threads<Other, OtherItem>(
+    self,
+    other: Other,
+  ) -> CombineLatestOptionsOpThreads<Self, Other>
+  where
+    Other: ObservableExt<OtherItem, Err>,
+  {
+    CombineLatestOptionsOpThreads::new(self, other)
+  }
+
   /// Returns an observable that, at the moment of subscription, will
   /// synchronously emit all values provided to this operator, then subscribe
   /// to the source and mirror all of its emissions to subscribers.
@@ -1713,6 +2966,34 @@ pub trait ObservableExt<Item, Err>: Sized {
     PairwiseOp { source: self }
   }
 
+  /// Emits the difference between each value and the previous one, like a
+  /// velocity computed from a stream of positions. Nothing is emitted for the
+  /// first value.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// observable::from_iter([1, 3, 7, 6])
+  ///   .derivative()
+  ///   .subscribe(|v| println!("{}", v));
+  ///
+  /// // Prints:
+  /// // 2
+  /// // 4
+  /// // -1
+  /// ```
+  fn derivative(self) -> DerivativeOp<Self, Item>
+  where
+    Item: Sub + Clone,
+  {
+    fn delta<Item: Sub>((prev, current): (Item, Item)) -> Item::Output {
+      current - prev
+    }
+    MapOp::new(self.pairwise(), delta as fn((Item, Item)) -> Item::Output)
+  }
+
   /// Used to perform side-effects for notifications from the source observable
   #[inline]
   fn tap<F>(self, f: F) -> TapOp<Self, F>
@@ -1722,6 +3003,49 @@ pub trait ObservableExt<Item, Err>: Sized {
     TapOp { source: self, func: f }
   }
 
+  /// Mirrors the source, and calls `callback` with the number of items
+  /// emitted since the last report every `interval`, to monitor the
+  /// throughput of a pipeline.
+  ///
+  /// The count of the last, partial interval is reported when the source
+  /// completes or emits an error.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  /// use futures::executor::LocalPool;
+  ///
+  /// let mut pool = LocalPool::new();
+  /// observable::interval(Duration::from_millis(1), pool.spawner())
+  ///   .take(10)
+  ///   .metered(
+  ///     Duration::from_millis(5),
+  ///     |count| println!("{} items in 5ms", count),
+  ///     pool.spawner(),
+  ///   )
+  ///   .subscribe(|_| {});
+  ///
+  /// pool.run();
+  /// ```
+  #[inline]
+  fn metered<F, SD>(
+    self,
+    interval: Duration,
+    callback: F,
+    scheduler: SD,
+  ) -> MeteredOp<Self, F, SD>
+  where
+    F: FnMut(usize),
+  {
+    MeteredOp {
+      source: self,
+      interval,
+      callback,
+      scheduler,
+    }
+  }
+
   /// Process the error of the observable and the return observable can't catch the error any more.
   #[inline]
   #[must_use]
@@ -1829,6 +3153,106 @@ pub trait ObservableExt<Item, Err>: Sized {
     ObservableFuture::new(self)
   }
 
+  /// Subscribes with a `next` callback, and returns the subscription with a
+  /// future that resolves when the stream terminates, so async code can
+  /// await a side-effecting subscription.
+  ///
+  /// The future resolves to `Ok(())` on complete and
+  /// `Err(SubscriptionFutureError::Error)` on error. If the subscription is
+  /// unsubscribed before the stream terminated, it resolves to
+  /// `Err(SubscriptionFutureError::Cancelled)`. Dropping the future lets the
+  /// source stop early, like an unsubscription.
+  ///
+  /// # Example
+  /// ```
+  /// use rxrust::{ops::future::SubscriptionFutureError, prelude::*};
+  /// use std::convert::Infallible;
+  ///
+  /// #[tokio::main]
+  /// async fn main() {
+  ///   let mut lines = vec![];
+  ///   let (_subscription, done) = observable::from_iter(0..3)
+  ///     .map(|i| format!("line {i}"))
+  ///     .subscribe_future(|line| lines.push(line));
+  ///
+  ///   assert_eq!(
+  ///     done.await,
+  ///     Ok::<_, SubscriptionFutureError<Infallible>>(())
+  ///   );
+  ///   assert_eq!(lines.len(), 3);
+  /// }
+  /// ```
+  #[inline]
+  fn subscribe_future<N>(
+    self,
+    next: N,
+  ) -> (Self::Unsub, SubscriptionFuture<Err>)
+  where
+    Self: Observable<Item, Err, SubscribeFutureObserver<N, Err>>,
+    N: FnMut(Item),
+  {
+    ops::future::subscribe_future(self, next)
+  }
+
+  /// Blocks the current thread until the source completes and returns all
+  /// the values it emitted, but gives up after `dur`.
+  ///
+  /// If the source doesn't finish in time, it's unsubscribed and
+  /// `TimeoutOrErr::Timeout` is returned. An error emitted by the source is
+  /// returned as `TimeoutOrErr::Error`. Handy to cap a pipeline in tests so
+  /// they can't hang forever.
+  ///
+  /// The source must emit on another thread, a source driven by a local
+  /// scheduler of the current thread can't make progress while blocked.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// let pool = FuturesThreadPoolScheduler::new().unwrap();
+  /// let values = observable::interval(Duration::from_millis(1), pool)
+  ///   .take(3)
+  ///   .subscribe_blocking_timeout(Duration::from_secs(1));
+  /// assert_eq!(values, Ok(vec![0, 1, 2]));
+  /// ```
+  #[cfg(not(target_arch = "wasm32"))]
+  fn subscribe_blocking_timeout(
+    self,
+    dur: Duration,
+  ) -> Result<Vec<Item>, TimeoutOrErr<Err>>
+  where
+    Self: Observable<Item, Err, BlockingObserver<Item, Err>>,
+  {
+    ops::blocking::subscribe_blocking_timeout(self, dur)
+  }
+
+  /// Blocks the current thread to collect the first `count` values of the
+  /// source, or the values emitted before `dur` elapsed, then unsubscribes
+  /// and returns them. It combines `take`, a timeout and a blocking collect
+  /// into one call, handy to probe a long living source in tests.
+  ///
+  /// Fewer values are returned if the source terminates earlier, an error of
+  /// the source is ignored.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// let pool = FuturesThreadPoolScheduler::new().unwrap();
+  /// let first3 = observable::interval(Duration::from_millis(1), pool)
+  ///   .take_collect(3, Duration::from_secs(1));
+  /// assert_eq!(first3, vec![0, 1, 2]);
+  /// ```
+  #[cfg(not(target_arch = "wasm32"))]
+  fn take_collect(self, count: usize, dur: Duration) -> Vec<Item>
+  where
+    Self: Observable<Item, Err, BlockingObserver<Item, Err>>,
+  {
+    ops::blocking::take_collect(self, count, dur)
+  }
+
   /// Converts this observable into a stream that emits the values of the observable.
   ///
   /// # Example
@@ -1856,6 +3280,47 @@ pub trait ObservableExt<Item, Err>: Sized {
   {
     ObservableStream::new(self)
   }
+
+  /// Converts this observable into a stream like `to_stream`, but buffers at
+  /// most `capacity` values waiting for the consumer, so a fast observable
+  /// can't grow the memory without limit.
+  ///
+  /// When the buffer is full, `policy` decides what happens:
+  /// `OverflowPolicy::Block` blocks the emitting thread until the consumer
+  /// catches up, and `OverflowPolicy::Error` ends the stream with a
+  /// `BoundedStreamError::Overflow` after the buffered values.
+  ///
+  /// With `OverflowPolicy::Block`, the observable must emit on another thread
+  /// than the consumer, or they wait for each other forever.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `capacity` is zero.
+  ///
+  /// # Example
+  /// ```
+  /// use rxrust::{ops::stream::OverflowPolicy, prelude::*};
+  /// use futures::{executor::block_on, StreamExt};
+  ///
+  /// let pool = FuturesThreadPoolScheduler::new().unwrap();
+  /// let stream = observable::from_iter(0..100)
+  ///   .subscribe_on(pool)
+  ///   .to_stream_bounded(8, OverflowPolicy::Block);
+  ///
+  /// let sum = block_on(stream.fold(0, |acc, v| async move { acc + v.unwrap() }));
+  /// assert_eq!(sum, 4950);
+  /// ```
+  #[inline]
+  fn to_stream_bounded(
+    self,
+    capacity: usize,
+    policy: OverflowPolicy,
+  ) -> BoundedObservableStream<Item, Err>
+  where
+    Self: Observable<Item, Err, BoundedStreamObserver<Item, Err>>,
+  {
+    BoundedObservableStream::new(self, capacity, policy)
+  }
 }
 
 #[cfg(test)]
@@ -4,6 +4,9 @@ use crate::prelude::*;
 /// that is supplied by a supplier-function which will be run once at each
 /// subscription
 ///
+/// Because the supplier runs again on each subscription, resubscribing
+/// operators like `retry` get a fresh observable for every attempt.
+///
 /// ```rust
 /// # use rxrust::prelude::*;
 ///
@@ -105,6 +108,51 @@ mod test {
     assert_eq!(*calls.lock().unwrap().deref(), 2);
   }
 
+  #[test]
+  fn retry_rerun_factory() {
+    let calls = Arc::new(Mutex::new(0));
+    let c_calls = calls.clone();
+    let mut values = vec![];
+    observable::defer(move || {
+      *c_calls.lock().unwrap() += 1;
+      let attempt = *c_calls.lock().unwrap();
+      observable::create(move |mut s: Subscriber<_>| {
+        if attempt < 3 {
+          s.error("network");
+        } else {
+          s.next(attempt);
+          s.complete();
+        }
+      })
+    })
+    .retry(5)
+    .on_error(|_| {})
+    .subscribe(|v| values.push(v));
+
+    assert_eq!(*calls.lock().unwrap(), 3);
+    assert_eq!(values, vec![3]);
+  }
+
+  #[test]
+  fn retry_threads_rerun_factory() {
+    use crate::ops::complete_status::CompleteStatus;
+
+    let calls = Arc::new(Mutex::new(0));
+    let c_calls = calls.clone();
+    let pool = FuturesThreadPoolScheduler::new().unwrap();
+    let (o, status) = observable::defer(move || {
+      *c_calls.lock().unwrap() += 1;
+      observable::create(|s: SubscriberThreads<_>| s.error("network"))
+    })
+    .subscribe_on(pool)
+    .retry_threads(2)
+    .complete_status();
+    o.on_error(|_| {}).subscribe(|_: i32| {});
+    CompleteStatus::wait_for_end(status);
+
+    assert_eq!(*calls.lock().unwrap(), 3);
+  }
+
   #[test]
   fn bench() {
     do_bench();
@@ -1,13 +1,18 @@
 use crate::{
   prelude::*,
   rc::{MutRc, RcDeref, RcDerefMut},
+  scheduler::local_remote_handle,
+};
+use futures::{future::LocalBoxFuture, task::noop_waker_ref, FutureExt};
+use std::{
+  collections::VecDeque, convert::Infallible, future::Future, task::Context,
 };
-use std::{collections::VecDeque, convert::Infallible};
 
 #[derive(Clone)]
 pub struct FakeClock(MutRc<InnerTimer>);
 
 struct InnerTimer {
+  origin: Instant,
   current: Instant,
   vec: VecDeque<TimerObserver>,
 }
@@ -23,6 +28,13 @@ enum TimerObserver {
     at: Instant,
     task: Box<dyn Publisher<Instant, Infallible>>,
   },
+  // A task of the scheduler, it's polled only once when it's expired. Tasks
+  // waiting for a real timer, like `RepeatTask`, never finish on virtual time
+  // and panic instead of being dropped silently.
+  Task {
+    at: Instant,
+    task: LocalBoxFuture<'static, ()>,
+  },
 }
 
 impl TimerObserver {
@@ -30,6 +42,7 @@ impl TimerObserver {
     match self {
       TimerObserver::Interval { at, .. } => *at,
       TimerObserver::Timer { at, .. } => *at,
+      TimerObserver::Task { at, .. } => *at,
     }
   }
 }
@@ -71,6 +84,15 @@ impl FakeClock {
           task.p_next(to);
           task.p_complete();
         }
+        TimerObserver::Task { mut task, .. } => {
+          let poll =
+            task.poll_unpin(&mut Context::from_waker(noop_waker_ref()));
+          assert!(
+            poll.is_ready(),
+            "FakeClock only runs tasks finishing in one poll, a task waiting \
+             for a real timer, like a `RepeatTask`, is not supported."
+          );
+        }
       }
     }
 
@@ -107,14 +129,41 @@ fn order_insert(tasks: &mut VecDeque<TimerObserver>, task: TimerObserver) {
 
 impl Default for FakeClock {
   fn default() -> Self {
+    let now = Instant::now();
     let inner = InnerTimer {
-      current: Instant::now(),
+      origin: now,
+      current: now,
       vec: Default::default(),
     };
     FakeClock(MutRc::own(inner))
   }
 }
 
+impl<T: Future + 'static> Scheduler<T> for FakeClock {
+  fn schedule(
+    &self,
+    task: T,
+    delay: Option<Duration>,
+  ) -> TaskHandle<T::Output> {
+    let (task, handle) = local_remote_handle(task);
+    let task = TimerObserver::Task {
+      at: self.current_time() + delay.unwrap_or_default(),
+      task,
+    };
+    self.clone().insert(task);
+    handle
+  }
+
+  #[inline]
+  fn now(&self) -> Instant {
+    self.current_time()
+  }
+
+  fn origin(&self) -> Instant {
+    self.0.rc_deref().origin
+  }
+}
+
 #[derive(Clone)]
 pub struct IntervalObservable {
   duration: Duration,
@@ -166,3 +215,17 @@ where
 }
 
 impl ObservableExt<Instant, Infallible> for DelayObservable {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  #[should_panic(expected = "FakeClock only runs tasks finishing in one poll")]
+  fn panic_on_task_waiting_real_timer() {
+    let clock = FakeClock::default();
+    observable::interval(Duration::from_millis(10), clock.clone())
+      .subscribe(|_| {});
+    clock.advance(Duration::from_millis(20));
+  }
+}
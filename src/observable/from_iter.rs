@@ -31,6 +31,21 @@ use std::{
 /// observable::from_iter(vec![0,1,2,3])
 ///   .subscribe(|v| {println!("{},", v)});
 /// ```
+///
+/// The iterator doesn't need to be `'static`, so borrowed data can be
+/// iterated without cloning it into an owned collection:
+///
+/// ```
+/// use rxrust::prelude::*;
+///
+/// let names = vec![String::from("a"), String::from("b")];
+/// let mut lens = 0;
+/// observable::from_iter(names.iter())
+///   .map(|name| name.len())
+///   .subscribe(|len| lens += len);
+///
+/// assert_eq!(lens, 2);
+/// ```
 pub fn from_iter<Iter>(iter: Iter) -> ObservableIter<Iter>
 where
   Iter: IntoIterator,
@@ -81,6 +96,8 @@ impl<Iter> ObservableExt<Iter::Item, Infallible> for ObservableIter<Iter> where
 /// // 123
 /// // 123
 /// ```
+// `repeat_n` would change the return type.
+#[allow(clippy::manual_repeat_n)]
 pub fn repeat<Item>(v: Item, n: usize) -> ObservableIter<Take<Repeat<Item>>>
 where
   Item: Clone,
@@ -88,6 +105,68 @@ where
   from_iter(std::iter::repeat(v).take(n))
 }
 
+/// Creates an infinite observable that emits `seed`, then `f(&seed)`, then
+/// `f(&f(&seed))`, and so on, like [`std::iter::successors`] without an end.
+///
+/// The values are emitted synchronously, and the emission stops as soon as
+/// the observer is finished, so use it with an operator that terminates the
+/// stream, like `take` or `take_while`. The next value is computed only after
+/// the current one is emitted and the observer still wants more, so `f` is
+/// never called past the last value taken. Never emits an error.
+///
+/// # Examples
+///
+/// ```
+/// use rxrust::prelude::*;
+///
+/// let mut powers = vec![];
+/// observable::iterate(1, |x| x * 2)
+///   .take(10)
+///   .subscribe(|v| powers.push(v));
+///
+/// assert_eq!(powers, [1, 2, 4, 8, 16, 32, 64, 128, 256, 512]);
+/// ```
+pub fn iterate<Item, F>(seed: Item, f: F) -> ObservableIterate<Item, F>
+where
+  Item: Clone,
+  F: FnMut(&Item) -> Item,
+{
+  ObservableIterate { seed, f }
+}
+
+#[derive(Clone)]
+pub struct ObservableIterate<Item, F> {
+  seed: Item,
+  f: F,
+}
+
+impl<O, Item, F> Observable<Item, Infallible, O> for ObservableIterate<Item, F>
+where
+  Item: Clone,
+  F: FnMut(&Item) -> Item,
+  O: Observer<Item, Infallible>,
+{
+  type Unsub = ();
+
+  fn actual_subscribe(self, mut observer: O) -> Self::Unsub {
+    let Self { mut seed, mut f } = self;
+    while !observer.is_finished() {
+      observer.next(seed.clone());
+      if observer.is_finished() {
+        break;
+      }
+      seed = f(&seed);
+    }
+  }
+}
+
+impl<Item, F> ObservableExt<Item, Infallible> for ObservableIterate<Item, F>
+where
+  Item: Clone,
+  F: FnMut(&Item) -> Item,
+{
+}
+
 #[cfg(test)]
 mod test {
   use crate::prelude::*;
@@ -117,6 +196,19 @@ mod test {
     assert!(completed);
   }
 
+  #[test]
+  fn from_borrowed_iter() {
+    let words = [String::from("hello"), String::from("world")];
+    let mut emitted: Vec<&str> = vec![];
+    observable::from_iter(words.iter())
+      .filter(|w| w.starts_with('w'))
+      .map(|w| w.as_str())
+      .subscribe(|w| emitted.push(w));
+
+    assert_eq!(emitted, ["world"]);
+    assert_eq!(words.len(), 2);
+  }
+
   #[test]
   fn repeat_three_times() {
     let mut hit_count = 0;
@@ -144,6 +236,46 @@ mod test {
     assert_eq!(0, hit_count);
     assert!(completed);
   }
+
+  #[test]
+  fn iterate_until_finished() {
+    let mut values = vec![];
+    let mut completed = false;
+    iterate(1, |x| x * 3)
+      .take_while(|v| *v < 100)
+      .on_complete(|| completed = true)
+      .subscribe(|v| values.push(v));
+
+    assert_eq!(values, vec![1, 3, 9, 27, 81]);
+    assert!(completed);
+  }
+
+  #[test]
+  fn iterate_owned_values() {
+    let mut values = vec![];
+    iterate(String::from("a"), |s| format!("{}a", s))
+      .take(3)
+      .subscribe(|v| values.push(v));
+
+    assert_eq!(values, vec!["a", "aa", "aaa"]);
+  }
+
+  #[test]
+  fn iterate_stop_before_next_value() {
+    let mut values = vec![];
+    let mut calls = 0;
+    // `128 * 2` overflows a `u8`, it's never computed.
+    iterate(1u8, |x| {
+      calls += 1;
+      x * 2
+    })
+    .take(8)
+    .subscribe(|v| values.push(v));
+
+    assert_eq!(values, vec![1, 2, 4, 8, 16, 32, 64, 128]);
+    assert_eq!(calls, 7);
+  }
+
   #[test]
   fn bench() {
     do_bench();
@@ -41,6 +41,9 @@ use super::{Observable, ObservableExt};
 /// // 3
 /// ```
 ///
+/// Unsubscribing drops the stream as soon as the executor gets to the task,
+/// it doesn't wait for the stream to yield again.
+///
 /// # Remarks
 /// If you want convert a `Stream` that can fail use [`rxrust::from_stream_result`] instead.
 pub fn from_stream<S, SD>(stream: S, scheduler: SD) -> StreamObservable<S, SD>
@@ -172,6 +175,28 @@ mod tests {
     assert_eq!(cur, vec![1, 2, 3])
   }
 
+  #[test]
+  fn unsubscribe_drop_stream() {
+    use futures::channel::mpsc::unbounded;
+
+    let (sender, receiver) = unbounded();
+    let mut scheduler = LocalPool::new();
+    let values = MutRc::own(vec![]);
+    let c_values = values.clone();
+    let subscription = from_stream(receiver, scheduler.spawner())
+      .subscribe(move |x| c_values.rc_deref_mut().push(x));
+
+    sender.unbounded_send(1).unwrap();
+    scheduler.run_until_stalled();
+    subscription.unsubscribe();
+    // the pending stream is dropped without waiting for another item.
+    scheduler.run();
+
+    assert!(sender.is_closed());
+    assert!(sender.unbounded_send(2).is_err());
+    assert_eq!(*values.rc_deref(), vec![1]);
+  }
+
   #[tokio::test]
   #[cfg(all(test, not(target_arch = "wasm32"), feature = "tokio-scheduler"))]
   async fn stream_channel_test() {
@@ -0,0 +1,252 @@
+use futures::Future;
+use std::{
+  convert::Infallible,
+  pin::Pin,
+  task::{Context, Poll},
+};
+
+use crate::prelude::*;
+
+/// Creates an observable that generates its values by running a state-driven
+/// loop, advancing one step per scheduler tick.
+///
+/// The loop starts with `initial`. At each step, as long as `condition`
+/// holds for the current state, the value produced by `result_selector` is
+/// emitted and the state is advanced by `iterate`. The observable completes
+/// once `condition` returns `false`.
+///
+/// Instead of running the loop synchronously, every step is a separate poll
+/// of the scheduled task, so the generation yields to the scheduler between
+/// emissions and stops as soon as the subscription is unsubscribed or the
+/// observer is finished.
+///
+/// # Example
+///
+/// ```
+/// use rxrust::prelude::*;
+/// use futures::executor::LocalPool;
+///
+/// let mut pool = LocalPool::new();
+/// observable::generate_async(
+///   (0, 1),
+///   |_| true,
+///   |(a, b)| (b, a + b),
+///   |(a, _)| *a,
+///   pool.spawner(),
+/// )
+/// .take(10)
+/// .subscribe(|v| println!("{}", v));
+///
+/// pool.run();
+/// // print logs: 0 1 1 2 3 5 8 13 21 34
+/// ```
+pub fn generate_async<Item, State, Cond, Iter, Sel, SD>(
+  initial: State,
+  condition: Cond,
+  iterate: Iter,
+  result_selector: Sel,
+  scheduler: SD,
+) -> GenerateObservable<State, Cond, Iter, Sel, SD>
+where
+  Cond: FnMut(&State) -> bool,
+  Iter: FnMut(State) -> State,
+  Sel: FnMut(&State) -> Item,
+{
+  GenerateObservable {
+    initial,
+    condition,
+    iterate,
+    result_selector,
+    scheduler,
+  }
+}
+
+#[derive(Clone)]
+pub struct GenerateObservable<State, Cond, Iter, Sel, SD> {
+  initial: State,
+  condition: Cond,
+  iterate: Iter,
+  result_selector: Sel,
+  scheduler: SD,
+}
+
+impl<Item, State, Cond, Iter, Sel, SD, O> Observable<Item, Infallible, O>
+  for GenerateObservable<State, Cond, Iter, Sel, SD>
+where
+  O: Observer<Item, Infallible>,
+  Cond: FnMut(&State) -> bool,
+  Iter: FnMut(State) -> State,
+  Sel: FnMut(&State) -> Item,
+  SD: Scheduler<GenerateFuture<State, Cond, Iter, Sel, O>>,
+{
+  type Unsub = TaskHandle<NormalReturn<()>>;
+
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    let Self {
+      initial,
+      condition,
+      iterate,
+      result_selector,
+      scheduler,
+    } = self;
+    let task = GenerateFuture {
+      state: Some(initial),
+      condition,
+      iterate,
+      result_selector,
+      observer: Some(observer),
+    };
+    scheduler.schedule(task, None)
+  }
+}
+
+impl<Item, State, Cond, Iter, Sel, SD> ObservableExt<Item, Infallible>
+  for GenerateObservable<State, Cond, Iter, Sel, SD>
+where
+  Sel: FnMut(&State) -> Item,
+{
+}
+
+pub struct GenerateFuture<State, Cond, Iter, Sel, O> {
+  state: Option<State>,
+  condition: Cond,
+  iterate: Iter,
+  result_selector: Sel,
+  observer: Option<O>,
+}
+
+// All the fields are only moved around by value and never pinned.
+impl<State, Cond, Iter, Sel, O> Unpin
+  for GenerateFuture<State, Cond, Iter, Sel, O>
+{
+}
+
+impl<Item, State, Cond, Iter, Sel, O> Future
+  for GenerateFuture<State, Cond, Iter, Sel, O>
+where
+  O: Observer<Item, Infallible>,
+  Cond: FnMut(&State) -> bool,
+  Iter: FnMut(State) -> State,
+  Sel: FnMut(&State) -> Item,
+{
+  type Output = NormalReturn<()>;
+
+  fn poll(
+    mut self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Self::Output> {
+    let this = &mut *self;
+    let (Some(observer), Some(state)) = (&mut this.observer, this.state.take())
+    else {
+      return Poll::Ready(NormalReturn::new(()));
+    };
+    if observer.is_finished() {
+      return Poll::Ready(NormalReturn::new(()));
+    }
+
+    if (this.condition)(&state) {
+      observer.next((this.result_selector)(&state));
+      this.state = Some((this.iterate)(state));
+      // yield to the scheduler before the next step.
+      cx.waker().wake_by_ref();
+      Poll::Pending
+    } else {
+      this.observer.take().unwrap().complete();
+      Poll::Ready(NormalReturn::new(()))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    prelude::*,
+    rc::{MutRc, RcDeref, RcDerefMut},
+  };
+  use futures::{executor::LocalPool, task::LocalSpawnExt};
+  use std::task::Poll;
+
+  #[test]
+  fn fibonacci() {
+    let mut pool = LocalPool::new();
+    let values = MutRc::own(vec![]);
+    let c_values = values.clone();
+    observable::generate_async(
+      (0, 1),
+      |_| true,
+      |(a, b)| (b, a + b),
+      |(a, _)| *a,
+      pool.spawner(),
+    )
+    .take(10)
+    .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    pool.run();
+    assert_eq!(*values.rc_deref(), vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+  }
+
+  #[test]
+  fn yield_between_emissions() {
+    let mut pool = LocalPool::new();
+    let values = MutRc::own(vec![]);
+    let c_values = values.clone();
+    observable::generate_async(
+      0,
+      |i| *i < 3,
+      |i| i + 1,
+      |i| *i,
+      pool.spawner(),
+    )
+    .subscribe(move |v| c_values.rc_deref_mut().push(v));
+    let c_values = values.clone();
+    observable::generate_async(
+      10,
+      |i| *i < 13,
+      |i| i + 1,
+      |i| *i,
+      pool.spawner(),
+    )
+    .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    assert!(values.rc_deref().is_empty());
+    pool.run();
+    assert_eq!(*values.rc_deref(), vec![0, 10, 1, 11, 2, 12]);
+  }
+
+  #[test]
+  fn unsubscribe_stop_generation() {
+    let mut pool = LocalPool::new();
+    let values = MutRc::own(vec![]);
+    let c_values = values.clone();
+    let subscription = observable::generate_async(
+      0,
+      |_| true,
+      |i| i + 1,
+      |i| *i,
+      pool.spawner(),
+    )
+    .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    let c_values = values.clone();
+    let stopped_at = MutRc::own(0);
+    let c_stopped_at = stopped_at.clone();
+    let mut subscription = Some(subscription);
+    pool
+      .spawner()
+      .spawn_local(futures::future::poll_fn(move |cx| {
+        let len = c_values.rc_deref().len();
+        if len < 3 {
+          cx.waker().wake_by_ref();
+          Poll::Pending
+        } else {
+          subscription.take().unwrap().unsubscribe();
+          *c_stopped_at.rc_deref_mut() = len;
+          Poll::Ready(())
+        }
+      }))
+      .unwrap();
+
+    pool.run();
+    assert_eq!(values.rc_deref().len(), *stopped_at.rc_deref());
+  }
+}
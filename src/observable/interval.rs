@@ -1,5 +1,14 @@
-use crate::prelude::*;
-use std::convert::Infallible;
+use crate::{
+  prelude::*,
+  rc::{MutArc, RcDeref, RcDerefMut},
+  scheduler::new_timer,
+};
+use futures::{Future, FutureExt};
+use std::{
+  convert::Infallible,
+  pin::Pin,
+  task::{Context, Poll},
+};
 
 /// Creates an observable which will fire at `dur` time into the future,
 /// and will repeat every `dur` interval after.
@@ -57,6 +66,268 @@ where
   }
 }
 
+/// Creates an observable which fires every `period`, aligned to the clock of
+/// `scheduler` rather than to the moment it's subscribed.
+///
+/// Every tick happens at a multiple of `period` since the origin of the clock,
+/// see [`Scheduler::origin`], so all the aligned intervals with the same
+/// period tick together, however late they were subscribed. The next tick is computed from the time of
+/// `scheduler` after every tick, so it doesn't drift either.
+///
+/// # Example
+///
+/// ```
+/// use rxrust::prelude::*;
+/// use futures::executor::LocalPool;
+///
+/// let mut pool = LocalPool::new();
+/// observable::interval_aligned(Duration::from_millis(10), pool.spawner())
+///   .take(3)
+///   .subscribe(|v| println!("{}", v));
+///
+/// pool.run();
+/// ```
+pub fn interval_aligned<S>(
+  period: Duration,
+  scheduler: S,
+) -> AlignedIntervalObservable<S> {
+  assert!(!period.is_zero(), "`period` must be greater than zero");
+  AlignedIntervalObservable { period, scheduler }
+}
+
+#[derive(Clone)]
+pub struct AlignedIntervalObservable<S> {
+  period: Duration,
+  scheduler: S,
+}
+
+type AlignedHandle = MutArc<Option<TaskHandle<NormalReturn<()>>>>;
+type AlignedArgs<O, S> = (MutArc<Option<O>>, AlignedHandle, usize, Duration, S);
+pub type AlignedIntervalTask<O, S> =
+  OnceTask<AlignedArgs<O, S>, NormalReturn<()>>;
+
+impl<S, O> Observable<usize, Infallible, O> for AlignedIntervalObservable<S>
+where
+  O: Observer<usize, Infallible>,
+  S: Scheduler<AlignedIntervalTask<O, S>>,
+{
+  type Unsub = AlignedIntervalSubscription<O>;
+
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    let observer = MutArc::own(Some(observer));
+    let handle = MutArc::own(None);
+    schedule_aligned((
+      observer.clone(),
+      handle.clone(),
+      0,
+      self.period,
+      self.scheduler,
+    ));
+    AlignedIntervalSubscription { observer, handle }
+  }
+}
+
+impl<S> ObservableExt<usize, Infallible> for AlignedIntervalObservable<S> {}
+
+/// Stops an aligned interval and cancels its pending tick.
+pub struct AlignedIntervalSubscription<O> {
+  observer: MutArc<Option<O>>,
+  handle: AlignedHandle,
+}
+
+impl<O> Subscription for AlignedIntervalSubscription<O> {
+  #[inline]
+  fn unsubscribe(self) {
+    self.observer.rc_deref_mut().take();
+    self.handle.unsubscribe();
+  }
+
+  #[inline]
+  fn is_closed(&self) -> bool {
+    self.observer.rc_deref().is_none()
+  }
+}
+
+// The delay from `now` to the next multiple of `period` since `origin`.
+fn next_aligned_delay(
+  origin: Instant,
+  now: Instant,
+  period: Duration,
+) -> Duration {
+  let period_nanos = period.as_nanos();
+  let nanos = if now >= origin {
+    period_nanos - (now - origin).as_nanos() % period_nanos
+  } else {
+    (origin - now).as_nanos() % period_nanos
+  };
+  if nanos == 0 {
+    period
+  } else {
+    Duration::from_nanos(nanos as u64)
+  }
+}
+
+fn schedule_aligned<O, S>(args: AlignedArgs<O, S>)
+where
+  O: Observer<usize, Infallible>,
+  S: Scheduler<AlignedIntervalTask<O, S>>,
+{
+  let scheduler = args.4.clone();
+  let handle = args.1.clone();
+  let delay = next_aligned_delay(scheduler.origin(), scheduler.now(), args.3);
+  let task = scheduler.schedule(OnceTask::new(aligned_task, args), Some(delay));
+  *handle.rc_deref_mut() = Some(task);
+}
+
+fn aligned_task<O, S>(
+  (mut observer, handle, seq, period, scheduler): AlignedArgs<O, S>,
+) -> NormalReturn<()>
+where
+  O: Observer<usize, Infallible>,
+  S: Scheduler<AlignedIntervalTask<O, S>>,
+{
+  if !observer.is_finished() {
+    observer.next(seq);
+  }
+  if !observer.is_finished() {
+    schedule_aligned((observer, handle, seq + 1, period, scheduler));
+  }
+  NormalReturn::new(())
+}
+
+/// Creates an observable which fires every `period` plus a jitter, to spread
+/// periodic load and avoid many instances ticking at the same moment.
+///
+/// Before every tick, `jitter` is called with `max_jitter` and the returned
+/// duration, clamped to `max_jitter`, is added to the period. rxRust doesn't
+/// ship a random number generator, so `jitter` is where you plug yours in.
+///
+/// # Example
+///
+/// ```
+/// use rxrust::prelude::*;
+/// use futures::executor::LocalPool;
+///
+/// let mut pool = LocalPool::new();
+/// let mut seed = 7u32;
+/// observable::interval_jittered(
+///   Duration::from_millis(2),
+///   Duration::from_millis(1),
+///   move |max| {
+///     // a toy linear congruential generator.
+///     seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+///     max.mul_f64((seed >> 16) as f64 / u16::MAX as f64)
+///   },
+///   pool.spawner(),
+/// )
+/// .take(3)
+/// .subscribe(|v| println!("{}", v));
+///
+/// pool.run();
+/// ```
+pub fn interval_jittered<J, S>(
+  period: Duration,
+  max_jitter: Duration,
+  jitter: J,
+  scheduler: S,
+) -> JitteredIntervalObservable<J, S>
+where
+  J: FnMut(Duration) -> Duration,
+{
+  JitteredIntervalObservable { period, max_jitter, jitter, scheduler }
+}
+
+#[derive(Clone)]
+pub struct JitteredIntervalObservable<J, S> {
+  period: Duration,
+  max_jitter: Duration,
+  jitter: J,
+  scheduler: S,
+}
+
+impl<J, S, O> Observable<usize, Infallible, O>
+  for JitteredIntervalObservable<J, S>
+where
+  O: Observer<usize, Infallible>,
+  J: FnMut(Duration) -> Duration,
+  S: Scheduler<JitteredIntervalTask<J, O>>,
+{
+  type Unsub = TaskHandle<NormalReturn<()>>;
+
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    let Self { period, max_jitter, jitter, scheduler } = self;
+    let mut task = JitteredIntervalTask {
+      fur: None,
+      period,
+      max_jitter,
+      jitter,
+      observer,
+      seq: 0,
+    };
+    task.fur = Some(task.next_timer());
+    scheduler.schedule(task, None)
+  }
+}
+
+impl<J, S> ObservableExt<usize, Infallible>
+  for JitteredIntervalObservable<J, S>
+{
+}
+
+pub struct JitteredIntervalTask<J, O> {
+  fur: Option<BoxFuture<'static, ()>>,
+  period: Duration,
+  max_jitter: Duration,
+  jitter: J,
+  observer: O,
+  seq: usize,
+}
+
+// The timer is boxed, and the other fields are never pinned.
+impl<J, O> Unpin for JitteredIntervalTask<J, O> {}
+
+impl<J, O> JitteredIntervalTask<J, O>
+where
+  J: FnMut(Duration) -> Duration,
+{
+  fn next_timer(&mut self) -> BoxFuture<'static, ()> {
+    new_timer(self.next_delay())
+  }
+
+  fn next_delay(&mut self) -> Duration {
+    let jitter = (self.jitter)(self.max_jitter).min(self.max_jitter);
+    self.period + jitter
+  }
+}
+
+impl<J, O> Future for JitteredIntervalTask<J, O>
+where
+  O: Observer<usize, Infallible>,
+  J: FnMut(Duration) -> Duration,
+{
+  type Output = NormalReturn<()>;
+
+  fn poll(
+    mut self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Self::Output> {
+    let this = &mut *self;
+    loop {
+      if let Some(fur) = this.fur.as_mut() {
+        futures::ready!(fur.poll_unpin(cx));
+      }
+      // don't arm another timer once the observer has had enough.
+      if !interval_task(&mut this.observer, this.seq)
+        || this.observer.is_finished()
+      {
+        return Poll::Ready(NormalReturn::new(()));
+      }
+      this.seq += 1;
+      this.fur = Some(this.next_timer());
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -87,6 +358,51 @@ mod tests {
     assert!(stamp.elapsed() > Duration::from_millis(5));
   }
 
+  #[test]
+  fn jitter_every_tick() {
+    let mut local = LocalPool::new();
+    let stamp = Instant::now();
+    let jitters = Arc::new(Mutex::new(vec![]));
+    let c_jitters = jitters.clone();
+    let ticks = Arc::new(Mutex::new(vec![]));
+    let c_ticks = ticks.clone();
+    let mut offsets = [1, 3, 50].into_iter();
+    interval_jittered(
+      Duration::from_millis(1),
+      Duration::from_millis(4),
+      move |max| {
+        c_jitters.lock().unwrap().push(max);
+        Duration::from_millis(offsets.next().unwrap_or(0))
+      },
+      local.spawner(),
+    )
+    .take(3)
+    .subscribe(move |v| c_ticks.lock().unwrap().push(v));
+    local.run();
+
+    assert_eq!(*ticks.lock().unwrap(), vec![0, 1, 2]);
+    // a jitter is drawn for every tick, with the max jitter as the bound.
+    assert_eq!(*jitters.lock().unwrap(), vec![Duration::from_millis(4); 3]);
+    // 1 + 1ms, 1 + 3ms, and 1 + 4ms for the jitter clamped to `max_jitter`.
+    assert!(stamp.elapsed() >= Duration::from_millis(11));
+  }
+
+  #[test]
+  fn jitter_clamped_to_max() {
+    let mut offsets = [1, 50].into_iter();
+    let mut task = JitteredIntervalTask {
+      fur: None,
+      period: Duration::from_millis(10),
+      max_jitter: Duration::from_millis(4),
+      jitter: move |_| Duration::from_millis(offsets.next().unwrap()),
+      observer: |_: usize| {},
+      seq: 0,
+    };
+
+    assert_eq!(task.next_delay(), Duration::from_millis(11));
+    assert_eq!(task.next_delay(), Duration::from_millis(14));
+  }
+
   #[test]
   fn local() {
     let mut local = LocalPool::new();
@@ -100,4 +416,57 @@ mod tests {
     assert_eq!(*ticks.lock().unwrap(), 5);
     assert!(stamp.elapsed() > Duration::from_millis(5));
   }
+
+  #[test]
+  fn aligned_across_subscriptions() {
+    use crate::observable::fake_timer::FakeClock;
+
+    let clock = FakeClock::default();
+    let origin = clock.current_time();
+    let period = Duration::from_millis(100);
+    let a = Arc::new(Mutex::new(vec![]));
+    let b = Arc::new(Mutex::new(vec![]));
+    let (c_a, c_clock) = (a.clone(), clock.clone());
+    interval_aligned(period, clock.clone()).subscribe(move |v| {
+      c_a
+        .lock()
+        .unwrap()
+        .push((v, c_clock.current_time() - origin))
+    });
+    clock.advance(Duration::from_millis(30));
+    let (c_b, c_clock) = (b.clone(), clock.clone());
+    interval_aligned(period, clock.clone()).subscribe(move |v| {
+      c_b
+        .lock()
+        .unwrap()
+        .push((v, c_clock.current_time() - origin))
+    });
+    clock.advance(Duration::from_millis(400));
+
+    let ticks: Vec<_> = (0..4).map(|i| (i, period * (i as u32 + 1))).collect();
+    assert_eq!(*a.lock().unwrap(), ticks);
+    // the later one ticks on the same ticks, not 30ms after.
+    assert_eq!(*b.lock().unwrap(), ticks);
+  }
+
+  #[test]
+  fn unsubscribe_aligned() {
+    use crate::observable::fake_timer::FakeClock;
+
+    let clock = FakeClock::default();
+    let ticks = Arc::new(Mutex::new(0));
+    let c_ticks = ticks.clone();
+    let subscription =
+      interval_aligned(Duration::from_millis(10), clock.clone())
+        .subscribe(move |_| *c_ticks.lock().unwrap() += 1);
+    clock.advance(Duration::from_millis(25));
+    assert_eq!(*ticks.lock().unwrap(), 2);
+
+    let pending = subscription.handle.clone();
+    assert!(!pending.is_closed());
+    subscription.unsubscribe();
+    assert!(pending.is_closed());
+    clock.advance(Duration::from_millis(100));
+    assert_eq!(*ticks.lock().unwrap(), 2);
+  }
 }
@@ -6,6 +6,9 @@ use crate::prelude::*;
 ///
 /// Completes immediately after emitting the values given. Never emits an error.
 ///
+/// The values are collected in an array, so they coerce to a common type the
+/// same way the elements of an array literal do.
+///
 /// # Arguments
 ///
 /// * `v` - A value to emits.
@@ -26,7 +29,7 @@ use crate::prelude::*;
 /// ```
 #[macro_export]
 macro_rules! of_sequence {
-    ( $( $item:expr ),* ) => {
+    ( $( $item:expr ),* $(,)? ) => {
   {
     $crate::observable::from_iter([$($item),*])
   }
@@ -295,6 +298,20 @@ mod test {
     assert_eq!(value, 6);
   }
 
+  #[test]
+  fn of_macros_unify_types() {
+    use std::fmt::Display;
+
+    let mut values = vec![];
+    let mut completed = false;
+    of_sequence!(&1 as &dyn Display, &"two", &3.5,)
+      .on_complete(|| completed = true)
+      .subscribe(|v| values.push(v.to_string()));
+
+    assert_eq!(values, vec!["1", "two", "3.5"]);
+    assert!(completed);
+  }
+
   #[test]
   fn bench() {
     do_bench();
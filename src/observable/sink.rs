@@ -0,0 +1,146 @@
+use crate::prelude::*;
+
+type SplitSubject<S> = (Sink<S>, SinkObservable<S>);
+
+/// Creates a `Subject` split into its two halves, an observer to push values
+/// imperatively, and an observable to consume them reactively. Every value
+/// pushed into the sink is emitted to all subscribers of the observable.
+///
+/// The halves share the subject, but the sink can only push values and the
+/// observable can only be subscribed to.
+///
+/// # Example
+/// ```
+/// use rxrust::prelude::*;
+///
+/// let (mut sink, events) = observable::sink::<i32, ()>();
+/// events
+///   .map(|v| v * 2)
+///   .on_error(|_| {})
+///   .subscribe(|v| println!("{}", v));
+///
+/// // push from a callback, for example.
+/// sink.next(1);
+/// sink.complete();
+///
+/// // Prints: 2
+/// ```
+pub fn sink<'a, Item, Err>() -> SplitSubject<Subject<'a, Item, Err>> {
+  let subject = Subject::default();
+  (Sink(subject.clone()), SinkObservable(subject))
+}
+
+/// A thread safe version of [`sink()`].
+pub fn sink_threads<Item, Err>() -> SplitSubject<SubjectThreads<Item, Err>> {
+  let subject = SubjectThreads::default();
+  (Sink(subject.clone()), SinkObservable(subject))
+}
+
+/// The observer half of a [`sink()`], pushes values to the subscribers of its
+/// observable half.
+#[derive(Clone)]
+pub struct Sink<S>(S);
+
+/// The observable half of a [`sink()`], emits the values pushed into the sink.
+#[derive(Clone)]
+pub struct SinkObservable<S>(S);
+
+impl<Item, Err, S> Observer<Item, Err> for Sink<S>
+where
+  S: Observer<Item, Err>,
+{
+  #[inline]
+  fn next(&mut self, value: Item) {
+    self.0.next(value)
+  }
+
+  #[inline]
+  fn error(self, err: Err) {
+    self.0.error(err)
+  }
+
+  #[inline]
+  fn complete(self) {
+    self.0.complete()
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.0.is_finished()
+  }
+}
+
+impl<Item, Err, O, S> Observable<Item, Err, O> for SinkObservable<S>
+where
+  S: Observable<Item, Err, O>,
+  O: Observer<Item, Err>,
+{
+  type Unsub = S::Unsub;
+
+  #[inline]
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    self.0.actual_subscribe(observer)
+  }
+}
+
+impl<Item, Err, S> ObservableExt<Item, Err> for SinkObservable<S> where
+  S: ObservableExt<Item, Err>
+{
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::prelude::*;
+  use std::{cell::RefCell, rc::Rc};
+
+  #[test]
+  fn push_to_all_subscribers() {
+    let values = Rc::new(RefCell::new(vec![]));
+    let completed = Rc::new(RefCell::new(0));
+    let (mut sink, observable) = observable::sink();
+
+    for _ in 0..2 {
+      let values = values.clone();
+      let completed = completed.clone();
+      observable
+        .clone()
+        .on_complete(move || *completed.borrow_mut() += 1)
+        .subscribe(move |v: i32| values.borrow_mut().push(v));
+    }
+
+    sink.next(1);
+    sink.next(2);
+    sink.complete();
+
+    assert_eq!(&*values.borrow(), &[1, 1, 2, 2]);
+    assert_eq!(*completed.borrow(), 2);
+  }
+
+  #[test]
+  fn push_error() {
+    let error = Rc::new(RefCell::new(None));
+    let c_error = error.clone();
+    let (sink, observable) = observable::sink::<i32, _>();
+    observable
+      .on_error(move |e| *c_error.borrow_mut() = Some(e))
+      .subscribe(|_| {});
+
+    sink.error("oops");
+    assert_eq!(*error.borrow(), Some("oops"));
+  }
+
+  #[test]
+  fn sink_threads() {
+    use crate::rc::{MutArc, RcDeref, RcDerefMut};
+
+    let values = MutArc::own(vec![]);
+    let c_values = values.clone();
+    let (mut sink, observable) = observable::sink_threads::<_, ()>();
+    observable
+      .on_error(|_| {})
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    std::thread::spawn(move || sink.next(1)).join().unwrap();
+    assert_eq!(*values.rc_deref(), vec![1]);
+  }
+}
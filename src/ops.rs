@@ -1,5 +1,8 @@
+#[cfg(not(target_arch = "wasm32"))]
+pub mod blocking;
 pub mod box_it;
 pub mod buffer;
+pub mod catch_error;
 pub mod collect;
 pub mod combine_latest;
 pub mod complete_status;
@@ -8,9 +11,12 @@ pub mod debounce;
 pub mod default_if_empty;
 pub mod delay;
 pub mod distinct;
+pub mod explode;
 pub mod filter;
 pub mod filter_map;
 pub mod finalize;
+pub mod first_all;
+pub mod first_or_timeout;
 pub mod future;
 pub mod group_by;
 pub mod last;
@@ -18,48 +24,64 @@ pub mod map;
 pub mod map_to;
 pub mod merge;
 pub mod merge_all;
+pub mod merge_all_ordered;
+pub mod merge_fair;
+pub mod metered;
 pub mod observe_on;
 pub mod on_complete;
 pub mod on_error;
 pub mod on_error_map;
 pub mod pairwise;
+pub mod reduce;
+pub mod reduce_sampled;
 pub mod ref_count;
+pub mod retry;
 pub mod sample;
 pub mod scan;
 pub mod skip;
 pub mod skip_last;
 pub mod skip_until;
 pub mod skip_while;
+pub mod slice;
 pub mod start_with;
 pub mod stream;
 pub mod subscribe_on;
+pub mod switch_all;
 pub mod take;
 pub mod take_last;
 pub mod take_until;
 pub mod take_while;
 pub mod tap;
 pub mod throttle;
+pub mod throttle_latest_by;
+pub mod timeout_restart;
 pub mod timestamp;
+pub mod window_count;
 pub mod with_latest_from;
 pub mod zip;
 
-use default_if_empty::DefaultIfEmptyOp;
+use filter::FilterOp;
 use last::LastOp;
 use map::MapOp;
+use pairwise::PairwiseOp;
+pub use reduce::ReduceOp;
 use scan::ScanOp;
+use std::ops::Sub;
 
-use self::merge_all::{MergeAllOp, MergeAllOpThreads};
+use self::{
+  first_all::{FirstAllOp, FirstAllOpThreads},
+  merge_all::{MergeAllOp, MergeAllOpThreads},
+  merge_all_ordered::{MergeAllOrderedOp, MergeAllOrderedOpThreads},
+  switch_all::{SwitchAllOp, SwitchAllOpThreads},
+};
 
 pub type CountOp<Source, Item> =
   ReduceOp<Source, fn(usize, Item) -> usize, usize, Item>;
+/// A composition of `filter` followed by `count`
+pub type CountWhereOp<Source, F, Item> = CountOp<FilterOp<Source, F>, Item>;
 pub type SumOp<Source, Item> =
   ReduceOp<Source, fn(Item, Item) -> Item, Item, Item>;
 
-// A composition of `scan` followed by `last`
-pub type ReduceOp<Source, BinaryOp, OutputItem, InputItem> = DefaultIfEmptyOp<
-  LastOp<ScanOp<Source, BinaryOp, OutputItem, InputItem>, OutputItem>,
-  OutputItem,
->;
 /// Realised as chained composition of scan->last->map operators.
 pub type MinMaxOp<Source, Item> = MapOp<
   LastOp<
@@ -84,6 +106,13 @@ pub type AverageOp<Source, Item> = MapOp<
   Accum<Item>,
 >;
 
+/// Realised as `pairwise` followed by `map`, emits the difference of each pair.
+pub type DerivativeOp<Source, Item> = MapOp<
+  PairwiseOp<Source>,
+  fn((Item, Item)) -> <Item as Sub>::Output,
+  (Item, Item),
+>;
+
 /// Returns an Observable that emits items based on applying a function that you
 /// supply to each item emitted by the source Observable, where that function
 /// returns an Observable, and then merging those resulting Observables and
@@ -93,6 +122,30 @@ pub type FlatMapOp<'a, Source, OutputItem, F, Input> =
 pub type FlatMapOpThreads<Source, OutputItem, F, Input> =
   MergeAllOpThreads<MapOp<Source, F, Input>, OutputItem>;
 
+/// Projects each source value to an Observable, runs a limited number of
+/// them concurrently, and emits their values in the order of the source,
+/// realised as `map` followed by `merge_all_ordered`.
+pub type MapAsyncOrderedOp<'a, Source, OutputItem, F, Input> =
+  MergeAllOrderedOp<'a, MapOp<Source, F, Input>, OutputItem>;
+pub type MapAsyncOrderedOpThreads<Source, OutputItem, F, Input> =
+  MergeAllOrderedOpThreads<MapOp<Source, F, Input>, OutputItem>;
+
+/// Projects each source value to an Observable and only emits the values of
+/// the most recently projected one, realised as `map` followed by
+/// `switch_all`.
+pub type SwitchMapOp<'a, Source, OutputItem, F, Input> =
+  SwitchAllOp<'a, MapOp<Source, F, Input>, OutputItem>;
+pub type SwitchMapOpThreads<Source, OutputItem, F, Input> =
+  SwitchAllOpThreads<MapOp<Source, F, Input>, OutputItem>;
+
+/// Projects each source value to an Observable and only emits its first value,
+/// one projection after another in the order of the source, realised as `map`
+/// followed by `first_all`.
+pub type MapAsyncOp<'a, Source, OutputItem, F, Input> =
+  FirstAllOp<'a, MapOp<Source, F, Input>, OutputItem>;
+pub type MapAsyncOpThreads<Source, OutputItem, F, Input> =
+  FirstAllOpThreads<MapOp<Source, F, Input>, OutputItem>;
+
 #[cfg(test)]
 mod test {
   use crate::prelude::*;
@@ -334,6 +387,30 @@ mod test {
     assert_eq!(0, emitted);
   }
 
+  #[test]
+  fn count_where() {
+    let mut emitted = 0;
+    observable::from_iter(vec![1, 7, 3, 0, 4])
+      .count_where(|v| *v > 2)
+      .subscribe(|v| emitted = v);
+    assert_eq!(3, emitted);
+  }
+
+  #[test]
+  fn count_where_no_match() {
+    let mut emitted = None;
+    observable::from_iter(vec![1, 7, 3, 0, 4])
+      .count_where(|v| *v > 10)
+      .subscribe(|v| emitted = Some(v));
+    assert_eq!(Some(0), emitted);
+
+    let mut emitted = None;
+    observable::empty()
+      .count_where(|_: &i32| true)
+      .subscribe(|v| emitted = Some(v));
+    assert_eq!(Some(0), emitted);
+  }
+
   #[test]
   fn average_of_floats() {
     let mut emitted = 0.0;
@@ -513,4 +590,44 @@ mod test {
 
     assert_eq!(left, right);
   }
+
+  #[test]
+  fn derivative() {
+    let mut emitted = vec![];
+    observable::from_iter([1, 3, 7, 6])
+      .derivative()
+      .subscribe(|v| emitted.push(v));
+
+    assert_eq!(emitted, vec![2, 4, -1]);
+  }
+
+  #[test]
+  fn derivative_of_single_value() {
+    let mut emitted = vec![];
+    let mut completed = false;
+    observable::of(1.5)
+      .derivative()
+      .on_complete(|| completed = true)
+      .subscribe(|v| emitted.push(v));
+
+    assert!(emitted.is_empty());
+    assert!(completed);
+  }
+
+  #[test]
+  fn derivative_to_other_type() {
+    use std::time::{Duration, Instant};
+
+    let start = Instant::now();
+    let mut emitted = vec![];
+    observable::from_iter([0, 10, 30])
+      .map(move |ms| start + Duration::from_millis(ms))
+      .derivative()
+      .subscribe(|v| emitted.push(v));
+
+    assert_eq!(
+      emitted,
+      vec![Duration::from_millis(10), Duration::from_millis(20)]
+    );
+  }
 }
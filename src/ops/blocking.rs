@@ -0,0 +1,182 @@
+use crate::prelude::*;
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+
+pub use crate::ops::timeout_restart::TimeoutOrErr;
+
+enum Event<Item, Err> {
+  Next(Item),
+  Error(Err),
+  Complete,
+}
+
+pub struct BlockingObserver<Item, Err> {
+  sender: Sender<Event<Item, Err>>,
+  closed: bool,
+}
+
+impl<Item, Err> Observer<Item, Err> for BlockingObserver<Item, Err> {
+  fn next(&mut self, value: Item) {
+    // the receiver is gone once the waiting side timed out.
+    self.closed = self.sender.send(Event::Next(value)).is_err();
+  }
+
+  #[inline]
+  fn error(self, err: Err) {
+    let _ = self.sender.send(Event::Error(err));
+  }
+
+  #[inline]
+  fn complete(self) {
+    let _ = self.sender.send(Event::Complete);
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.closed
+  }
+}
+
+pub(crate) fn subscribe_blocking_timeout<Item, Err, S>(
+  source: S,
+  dur: Duration,
+) -> Result<Vec<Item>, TimeoutOrErr<Err>>
+where
+  S: Observable<Item, Err, BlockingObserver<Item, Err>>,
+{
+  let deadline = Instant::now() + dur;
+  let (sender, receiver) = channel();
+  let subscription =
+    source.actual_subscribe(BlockingObserver { sender, closed: false });
+
+  let mut values = vec![];
+  loop {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    match receiver.recv_timeout(remaining) {
+      Ok(Event::Next(v)) => values.push(v),
+      Ok(Event::Error(err)) => return Err(TimeoutOrErr::Error(err)),
+      // the source dropped the observer without an error, nothing more will
+      // be emitted.
+      Ok(Event::Complete) | Err(RecvTimeoutError::Disconnected) => {
+        return Ok(values)
+      }
+      Err(RecvTimeoutError::Timeout) => {
+        subscription.unsubscribe();
+        return Err(TimeoutOrErr::Timeout);
+      }
+    }
+  }
+}
+
+pub(crate) fn take_collect<Item, Err, S>(
+  source: S,
+  count: usize,
+  dur: Duration,
+) -> Vec<Item>
+where
+  S: Observable<Item, Err, BlockingObserver<Item, Err>>,
+{
+  let deadline = Instant::now() + dur;
+  let (sender, receiver) = channel();
+  let subscription =
+    source.actual_subscribe(BlockingObserver { sender, closed: false });
+
+  let mut values = vec![];
+  while values.len() < count {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    match receiver.recv_timeout(remaining) {
+      Ok(Event::Next(v)) => values.push(v),
+      Err(RecvTimeoutError::Timeout) => break,
+      Ok(Event::Error(_) | Event::Complete)
+      | Err(RecvTimeoutError::Disconnected) => return values,
+    }
+  }
+  subscription.unsubscribe();
+  values
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  #[test]
+  fn collect_before_timeout() {
+    let values =
+      observable::from_iter(0..5).subscribe_blocking_timeout(Duration::ZERO);
+    assert_eq!(values, Ok(vec![0, 1, 2, 3, 4]));
+  }
+
+  #[test]
+  fn source_error() {
+    let res = observable::create(|mut s: SubscriberThreads<_>| {
+      s.next(1);
+      s.error("boom");
+    })
+    .subscribe_blocking_timeout(Duration::from_secs(1));
+    assert_eq!(res, Err(TimeoutOrErr::Error("boom")));
+  }
+
+  #[test]
+  fn wait_async_source() {
+    let pool = FuturesThreadPoolScheduler::new().unwrap();
+    let values = observable::interval(Duration::from_millis(1), pool)
+      .take(3)
+      .subscribe_blocking_timeout(Duration::from_secs(10));
+    assert_eq!(values, Ok(vec![0, 1, 2]));
+  }
+
+  #[test]
+  fn unsubscribe_on_timeout() {
+    let mut subject = SubjectThreads::<i32, ()>::default();
+    let res = subject
+      .clone()
+      .subscribe_blocking_timeout(Duration::from_millis(10));
+    assert_eq!(res, Err(TimeoutOrErr::Timeout));
+
+    subject.next(1);
+    subject.retain();
+    assert!(subject.is_empty());
+  }
+
+  #[test]
+  fn take_collect_first_items() {
+    let pool = FuturesThreadPoolScheduler::new().unwrap();
+    let values = observable::interval(Duration::from_millis(1), pool)
+      .take_collect(3, Duration::from_secs(10));
+    assert_eq!(values, vec![0, 1, 2]);
+  }
+
+  #[test]
+  fn unsubscribe_after_collected() {
+    let mut subject = SubjectThreads::<i32, ()>::default();
+    let values = subject
+      .clone()
+      .start_with(vec![1, 2, 3])
+      .take_collect(2, Duration::from_secs(1));
+    assert_eq!(values, vec![1, 2]);
+
+    subject.next(4);
+    subject.retain();
+    assert!(subject.is_empty());
+  }
+
+  #[test]
+  fn take_collect_until_timeout() {
+    let mut subject = SubjectThreads::<i32, ()>::default();
+    let values = subject
+      .clone()
+      .start_with(vec![0])
+      .take_collect(3, Duration::from_millis(10));
+    assert_eq!(values, vec![0]);
+
+    subject.next(1);
+    subject.retain();
+    assert!(subject.is_empty());
+  }
+
+  #[test]
+  fn take_collect_stop_on_complete() {
+    let values =
+      observable::from_iter(0..2).take_collect(5, Duration::from_secs(1));
+    assert_eq!(values, vec![0, 1]);
+  }
+}
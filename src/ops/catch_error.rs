@@ -0,0 +1,201 @@
+use crate::prelude::*;
+
+/// Recovers from an error with a fallback observable, or rethrows it. See
+/// [`ObservableExt::catch_error_map`].
+#[derive(Clone)]
+pub struct CatchErrorMapOp<'a, S, F, Err> {
+  source: S,
+  handler: F,
+  _hint: TypeHint<(&'a (), Err)>,
+}
+
+/// The thread safe version of [`CatchErrorMapOp`].
+#[derive(Clone)]
+pub struct CatchErrorMapOpThreads<S, F, Err> {
+  source: S,
+  handler: F,
+  _hint: TypeHint<Err>,
+}
+
+pub struct CatchErrorMapObserver<'a, O, F> {
+  observer: O,
+  handler: F,
+  subscription: MultiSubscription<'a>,
+}
+
+pub struct CatchErrorMapObserverThreads<O, F> {
+  observer: O,
+  handler: F,
+  subscription: MultiSubscriptionThreads,
+}
+
+macro_rules! impl_catch_error_map {
+  ($op: ident, $observer: ident, $subscription: ty, $box_unsub: ty,
+    $($lf: lifetime)? $(,$send: ident)?) => {
+    impl<$($lf,)? S, F, Err> $op<$($lf,)? S, F, Err> {
+      #[inline]
+      pub(crate) fn new(source: S, handler: F) -> Self {
+        Self { source, handler, _hint: TypeHint::default() }
+      }
+    }
+
+    impl<$($lf,)? Item, Err, OutErr, O, S, F, Fallback>
+      Observable<Item, OutErr, O> for $op<$($lf,)? S, F, Err>
+    where
+      O: Observer<Item, OutErr>,
+      S: Observable<Item, Err, $observer<$($lf,)? O, F>>,
+      S::Unsub: $($lf)? $($send + 'static)?,
+      F: FnMut(Err) -> Result<Fallback, OutErr>,
+      Fallback: Observable<Item, OutErr, O>,
+      Fallback::Unsub: $($lf)? $($send + 'static)?,
+    {
+      type Unsub = $subscription;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let mut subscription = <$subscription>::default();
+        let unsub = self.source.actual_subscribe($observer {
+          observer,
+          handler: self.handler,
+          subscription: subscription.clone(),
+        });
+        subscription.append(<$box_unsub>::new(unsub));
+        subscription
+      }
+    }
+
+    impl<$($lf,)? Item, Err, OutErr, S, F, Fallback> ObservableExt<Item, OutErr>
+      for $op<$($lf,)? S, F, Err>
+    where
+      S: ObservableExt<Item, Err>,
+      F: FnMut(Err) -> Result<Fallback, OutErr>,
+    {
+    }
+
+    impl<$($lf,)? Item, Err, OutErr, O, F, Fallback> Observer<Item, Err>
+      for $observer<$($lf,)? O, F>
+    where
+      O: Observer<Item, OutErr>,
+      F: FnMut(Err) -> Result<Fallback, OutErr>,
+      Fallback: Observable<Item, OutErr, O>,
+      Fallback::Unsub: $($lf)? $($send + 'static)?,
+    {
+      #[inline]
+      fn next(&mut self, value: Item) {
+        self.observer.next(value)
+      }
+
+      fn error(mut self, err: Err) {
+        match (self.handler)(err) {
+          Ok(fallback) => {
+            let unsub = fallback.actual_subscribe(self.observer);
+            self.subscription.append(<$box_unsub>::new(unsub));
+          }
+          Err(err) => self.observer.error(err),
+        }
+      }
+
+      #[inline]
+      fn complete(self) {
+        self.observer.complete()
+      }
+
+      #[inline]
+      fn is_finished(&self) -> bool {
+        self.observer.is_finished()
+      }
+    }
+  };
+}
+
+impl_catch_error_map!(
+  CatchErrorMapOp,
+  CatchErrorMapObserver,
+  MultiSubscription<'a>,
+  BoxSubscription<'a>,
+  'a
+);
+impl_catch_error_map!(
+  CatchErrorMapOpThreads,
+  CatchErrorMapObserverThreads,
+  MultiSubscriptionThreads,
+  BoxSubscriptionThreads,
+  ,
+  Send
+);
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+
+  #[derive(Debug, PartialEq)]
+  enum FetchError {
+    Timeout,
+    Unauthorized,
+  }
+
+  #[test]
+  fn recover_with_fallback() {
+    let mut values = vec![];
+    let mut completed = false;
+    observable::create(|mut subscriber: Subscriber<_>| {
+      subscriber.next(1);
+      subscriber.error(FetchError::Timeout);
+    })
+    .catch_error_map(|_| Ok(observable::of_result::<_, FetchError>(Ok(0))))
+    .on_complete(|| completed = true)
+    .on_error(|_| unreachable!())
+    .subscribe(|v| values.push(v));
+
+    assert_eq!(values, vec![1, 0]);
+    assert!(completed);
+  }
+
+  #[test]
+  fn rethrow_transformed_error() {
+    let mut error = None;
+    observable::throw(FetchError::Unauthorized)
+      .catch_error_map(|e| match e {
+        FetchError::Timeout => Ok(observable::of_result(Ok(()))),
+        e => Err(format!("{:?}", e)),
+      })
+      .on_error(|e| error = Some(e))
+      .subscribe(|_| {});
+
+    assert_eq!(error.as_deref(), Some("Unauthorized"));
+  }
+
+  #[test]
+  fn unsubscribe_fallback() {
+    let mut source = Subject::<i32, ()>::default();
+    let mut fallback = Subject::<i32, ()>::default();
+    let c_fallback = fallback.clone();
+
+    let subscription = source
+      .clone()
+      .catch_error_map(move |_| Ok::<_, ()>(c_fallback.clone()))
+      .on_error(|_| {})
+      .subscribe(|_| panic!("unsubscribed"));
+
+    source.clone().error(());
+    subscription.unsubscribe();
+    fallback.next(1);
+    source.next(1);
+  }
+
+  #[test]
+  fn catch_error_map_threads() {
+    use crate::rc::{MutArc, RcDeref, RcDerefMut};
+
+    let values = MutArc::own(vec![]);
+    let c_values = values.clone();
+    observable::throw(FetchError::Timeout)
+      .map(|_| 1)
+      .catch_error_map_threads(|_| {
+        Ok(observable::of_result::<_, FetchError>(Ok(2)))
+      })
+      .on_error(|_| {})
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    assert_eq!(*values.rc_deref(), vec![2]);
+  }
+}
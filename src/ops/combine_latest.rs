@@ -2,6 +2,7 @@ use crate::{
   prelude::*,
   rc::{MutArc, MutRc, RcDeref, RcDerefMut},
 };
+use std::collections::VecDeque;
 
 #[derive(Clone)]
 pub struct CombineLatestOp<A, B, BinaryOp> {
@@ -44,8 +45,10 @@ macro_rules! impl_combine_latest_op {
         Err,
         BObserver<$rc<CombineLatestObserver<O, ItemA, ItemB, BinaryOp>>, ItemA>,
       >,
-      $rc<CombineLatestObserver<O, ItemA, ItemB, BinaryOp>>:
-        Observer<CombineItem<ItemA, ItemB>, Err>,
+      AObserver<$rc<CombineLatestObserver<O, ItemA, ItemB, BinaryOp>>, ItemB>:
+        Observer<ItemA, Err>,
+      BObserver<$rc<CombineLatestObserver<O, ItemA, ItemB, BinaryOp>>, ItemA>:
+        Observer<ItemB, Err>,
     {
       type Unsub = ZipSubscription<A::Unsub, B::Unsub>;
 
@@ -163,60 +166,504 @@ impl_combine_latest_observer!(MutRc);
 impl_combine_latest_observer!(MutArc);
 pub struct AObserver<O, B>(O, TypeHint<B>);
 
-impl<O, A, B, Err> Observer<A, Err> for AObserver<O, B>
-where
-  O: Observer<CombineItem<A, B>, Err>,
-{
-  #[inline]
-  fn next(&mut self, value: A) {
-    self.0.next(CombineItem::ItemA(value));
+pub struct BObserver<O, A>(O, TypeHint<A>);
+
+macro_rules! impl_side_observer {
+  ($side: ident, $rc: ident, $item: ident, $field: ident, $variant: ident) => {
+    impl<O, A, B, OutputItem, BinaryOp, Err> Observer<$item, Err>
+      for $side<$rc<CombineLatestObserver<O, A, B, BinaryOp>>, impl_side_observer!(@other $item)>
+    where
+      O: Observer<OutputItem, Err>,
+      BinaryOp: FnMut(A, B) -> OutputItem,
+      A: Clone,
+      B: Clone,
+    {
+      #[inline]
+      fn next(&mut self, value: $item) {
+        self.0.next(CombineItem::$variant(value));
+      }
+
+      #[inline]
+      fn error(self, err: Err) {
+        self.0.error(err)
+      }
+
+      fn complete(self) {
+        let mut inner = self.0.rc_deref_mut();
+        // a side completed without any value, no combined value can form.
+        if inner.$field.is_none() {
+          let observer = inner.observer.take();
+          drop(inner);
+          if let Some(observer) = observer {
+            observer.complete();
+          }
+        } else {
+          drop(inner);
+          self.0.complete()
+        }
+      }
+
+      #[inline]
+      fn is_finished(&self) -> bool {
+        self.0.is_finished()
+      }
+    }
+  };
+  (@other A) => { B };
+  (@other B) => { A };
+}
+
+impl_side_observer!(AObserver, MutRc, A, a, ItemA);
+impl_side_observer!(AObserver, MutArc, A, a, ItemA);
+impl_side_observer!(BObserver, MutRc, B, b, ItemB);
+impl_side_observer!(BObserver, MutArc, B, b, ItemB);
+
+/// Combines the latest items of two observables as `Option`s, emits on every
+/// item of either side, even before the other side emitted. See
+/// [`ObservableExt::combine_latest_options`].
+#[derive(Clone)]
+pub struct CombineLatestOptionsOp<A, B> {
+  a: A,
+  b: B,
+}
+
+/// The thread safe version of [`CombineLatestOptionsOp`].
+#[derive(Clone)]
+pub struct CombineLatestOptionsOpThreads<A, B> {
+  a: A,
+  b: B,
+}
+
+pub struct CombineLatestOptionsObserver<O, A, B, Err> {
+  downstream: Downstream<O, (Option<A>, Option<B>), Err>,
+  a: Option<A>,
+  b: Option<B>,
+  completed_one: bool,
+}
+
+// The downstream observer of a state shared by many sources. It's called
+// without the state borrowed, a source emitting meanwhile, e.g. pushed by
+// the downstream itself, queues its events for the emitting call.
+struct Downstream<O, Item, Err> {
+  observer: Option<O>,
+  queue: VecDeque<Item>,
+  termination: Option<Result<(), Err>>,
+  // someone is emitting to the downstream, the observer is taken out.
+  emitting: bool,
+}
+
+impl<O, Item, Err> Downstream<O, Item, Err> {
+  fn new(observer: O) -> Self {
+    Downstream {
+      observer: Some(observer),
+      queue: VecDeque::new(),
+      termination: None,
+      emitting: false,
+    }
+  }
+
+  fn is_closed(&self) -> bool {
+    self.observer.is_none() && !self.emitting
   }
 
-  #[inline]
-  fn error(self, err: Err) {
-    self.0.error(err)
+  // Queues a value, returns if the caller has to drain the queue.
+  fn push(&mut self, value: Item) -> bool {
+    if self.is_closed() || self.termination.is_some() {
+      return false;
+    }
+    self.queue.push_back(value);
+    self.start_emitting()
+  }
+
+  // Queues the termination, returns if the caller has to drain the queue.
+  fn terminate(&mut self, termination: Result<(), Err>) -> bool {
+    if self.is_closed() || self.termination.is_some() {
+      return false;
+    }
+    self.termination = Some(termination);
+    self.start_emitting()
   }
 
-  #[inline]
-  fn complete(self) {
-    self.0.complete()
+  fn start_emitting(&mut self) -> bool {
+    !std::mem::replace(&mut self.emitting, true)
   }
 
-  #[inline]
-  fn is_finished(&self) -> bool {
-    self.0.is_finished()
+  fn is_finished(&self) -> bool
+  where
+    O: Observer<Item, Err>,
+  {
+    !self.emitting && self.observer.as_ref().is_none_or(|o| o.is_finished())
   }
 }
 
-pub struct BObserver<O, A>(O, TypeHint<A>);
+// Emits the queued events of the downstream of `state`, borrowing the state
+// only between the events.
+fn drain<R, S, O, Item, Err>(
+  state: &R,
+  downstream: fn(&mut S) -> &mut Downstream<O, Item, Err>,
+) where
+  R: RcDerefMut<Target = S>,
+  O: Observer<Item, Err>,
+{
+  loop {
+    let mut guard = state.rc_deref_mut();
+    let d = downstream(&mut guard);
+    if let Some(value) = d.queue.pop_front() {
+      let observer = d.observer.take();
+      drop(guard);
+      if let Some(mut observer) = observer {
+        observer.next(value);
+        downstream(&mut state.rc_deref_mut()).observer = Some(observer);
+      }
+    } else {
+      d.emitting = false;
+      let termination = d.termination.take();
+      let observer = termination.as_ref().and_then(|_| d.observer.take());
+      drop(guard);
+      match (termination, observer) {
+        (Some(Ok(())), Some(observer)) => observer.complete(),
+        (Some(Err(err)), Some(observer)) => observer.error(err),
+        _ => {}
+      }
+      return;
+    }
+  }
+}
+
+macro_rules! impl_combine_latest_options_op {
+  ($name: ident, $rc: ident) => {
+    impl<A, B> $name<A, B> {
+      #[inline]
+      pub(crate) fn new(a: A, b: B) -> Self {
+        $name { a, b }
+      }
+    }
+
+    impl<A, B, ItemA, ItemB, Err, O>
+      Observable<(Option<ItemA>, Option<ItemB>), Err, O> for $name<A, B>
+    where
+      O: Observer<(Option<ItemA>, Option<ItemB>), Err>,
+      A: Observable<
+        ItemA,
+        Err,
+        AObserver<
+          $rc<CombineLatestOptionsObserver<O, ItemA, ItemB, Err>>,
+          ItemB,
+        >,
+      >,
+      B: Observable<
+        ItemB,
+        Err,
+        BObserver<
+          $rc<CombineLatestOptionsObserver<O, ItemA, ItemB, Err>>,
+          ItemA,
+        >,
+      >,
+      ItemA: Clone,
+      ItemB: Clone,
+    {
+      type Unsub = ZipSubscription<A::Unsub, B::Unsub>;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let o_combine = $rc::own(CombineLatestOptionsObserver {
+          downstream: Downstream::new(observer),
+          a: None,
+          b: None,
+          completed_one: false,
+        });
+        let a_unsub = self
+          .a
+          .actual_subscribe(AObserver(o_combine.clone(), TypeHint::new()));
+        let b_unsub = self
+          .b
+          .actual_subscribe(BObserver(o_combine, TypeHint::new()));
+
+        ZipSubscription::new(a_unsub, b_unsub)
+      }
+    }
+
+    impl<A, B, ItemA, ItemB, Err>
+      ObservableExt<(Option<ItemA>, Option<ItemB>), Err> for $name<A, B>
+    where
+      A: ObservableExt<ItemA, Err>,
+      B: ObservableExt<ItemB, Err>,
+    {
+    }
+  };
+}
+
+impl_combine_latest_options_op!(CombineLatestOptionsOp, MutRc);
+impl_combine_latest_options_op!(CombineLatestOptionsOpThreads, MutArc);
+
+macro_rules! impl_options_side_observer {
+  ($side: ident, $rc: ident, $item: ident, $field: ident) => {
+    impl<O, A, B, Err> Observer<$item, Err>
+      for $side<$rc<CombineLatestOptionsObserver<O, A, B, Err>>, impl_side_observer!(@other $item)>
+    where
+      O: Observer<(Option<A>, Option<B>), Err>,
+      A: Clone,
+      B: Clone,
+    {
+      fn next(&mut self, value: $item) {
+        let mut inner = self.0.rc_deref_mut();
+        inner.$field = Some(value);
+        let latest = (inner.a.clone(), inner.b.clone());
+        if inner.downstream.push(latest) {
+          drop(inner);
+          drain(&self.0, |inner| &mut inner.downstream);
+        }
+      }
+
+      fn error(self, err: Err) {
+        let need_drain = self.0.rc_deref_mut().downstream.terminate(Err(err));
+        if need_drain {
+          drain(&self.0, |inner| &mut inner.downstream);
+        }
+      }
+
+      fn complete(self) {
+        let mut inner = self.0.rc_deref_mut();
+        if !inner.completed_one {
+          inner.completed_one = true;
+        } else if inner.downstream.terminate(Ok(())) {
+          drop(inner);
+          drain(&self.0, |inner| &mut inner.downstream);
+        }
+      }
+
+      fn is_finished(&self) -> bool {
+        self.0.rc_deref().downstream.is_finished()
+      }
+    }
+  };
+}
 
-impl<O, A, B, Err> Observer<B, Err> for BObserver<O, A>
+impl_options_side_observer!(AObserver, MutRc, A, a);
+impl_options_side_observer!(AObserver, MutArc, A, a);
+impl_options_side_observer!(BObserver, MutRc, B, b);
+impl_options_side_observer!(BObserver, MutArc, B, b);
+
+/// Combines many sources of the same item type, and emits the result of
+/// `combine` over the latest values of all the sources whenever one of them
+/// emits, once every source has emitted at least once.
+///
+/// `combine` receives the latest values as a slice, in the order of
+/// `sources`, so a scalar like a sum can be computed without collecting a
+/// `Vec` on every emission.
+///
+/// It completes when all the sources completed, or as soon as one of them
+/// completes without emitting any value.
+///
+/// # Example
+///
+/// ```
+/// use rxrust::prelude::*;
+/// use std::convert::Infallible;
+///
+/// let mut sums = vec![];
+/// let mut sensors: Vec<Subject<i32, Infallible>> =
+///   (0..3).map(|_| Subject::default()).collect();
+/// observable::combine_latest_many(sensors.clone(), |latest: &[i32]| {
+///   latest.iter().sum::<i32>()
+/// })
+/// .subscribe(|v| sums.push(v));
+///
+/// sensors[0].next(1);
+/// sensors[1].next(2);
+/// sensors[2].next(3);
+/// sensors[0].next(10);
+/// drop(sensors);
+/// assert_eq!(sums, vec![6, 15]);
+/// ```
+pub fn combine_latest_many<S, F, Item, OutputItem>(
+  sources: impl IntoIterator<Item = S>,
+  combine: F,
+) -> CombineLatestManyOp<S, F, Item>
 where
-  O: Observer<CombineItem<A, B>, Err>,
+  F: FnMut(&[Item]) -> OutputItem,
 {
-  fn next(&mut self, value: B) {
-    self.0.next(CombineItem::ItemB(value));
-  }
+  CombineLatestManyOp::new(sources.into_iter().collect(), combine)
+}
 
-  #[inline]
-  fn error(self, err: Err) {
-    self.0.error(err)
-  }
+/// The thread safe version of [`combine_latest_many`].
+pub fn combine_latest_many_threads<S, F, Item, OutputItem>(
+  sources: impl IntoIterator<Item = S>,
+  combine: F,
+) -> CombineLatestManyOpThreads<S, F, Item>
+where
+  F: FnMut(&[Item]) -> OutputItem,
+{
+  CombineLatestManyOpThreads::new(sources.into_iter().collect(), combine)
+}
 
-  #[inline]
-  fn complete(self) {
-    self.0.complete()
+#[derive(Clone)]
+pub struct CombineLatestManyOp<S, F, Item> {
+  sources: Vec<S>,
+  combine: F,
+  _hint: TypeHint<Item>,
+}
+
+#[derive(Clone)]
+pub struct CombineLatestManyOpThreads<S, F, Item> {
+  sources: Vec<S>,
+  combine: F,
+  _hint: TypeHint<Item>,
+}
+
+pub struct ManySubscription<U>(pub(crate) Vec<U>);
+
+impl<U: Subscription> Subscription for ManySubscription<U> {
+  fn unsubscribe(self) {
+    self.0.into_iter().for_each(|u| u.unsubscribe());
   }
 
-  #[inline]
-  fn is_finished(&self) -> bool {
-    self.0.is_finished()
+  fn is_closed(&self) -> bool {
+    self.0.iter().all(|u| u.is_closed())
   }
 }
 
+pub struct CombineLatestManyState<O, Item, F, OutputItem, Err> {
+  downstream: Downstream<O, OutputItem, Err>,
+  // the latest values, until every source has emitted once.
+  pending: Vec<Option<Item>>,
+  // the latest values of all the sources, updated in place.
+  latest: Vec<Item>,
+  combine: F,
+  sources: usize,
+  completed: usize,
+}
+
+pub struct CombineLatestManyObserver<State> {
+  state: State,
+  index: usize,
+}
+
+macro_rules! impl_combine_latest_many_op {
+  ($name: ident, $rc: ident) => {
+    impl<S, F, Item> $name<S, F, Item> {
+      #[inline]
+      pub(crate) fn new(sources: Vec<S>, combine: F) -> Self {
+        $name { sources, combine, _hint: TypeHint::new() }
+      }
+    }
+
+    impl<Item, OutputItem, Err, O, S, F> Observable<OutputItem, Err, O>
+      for $name<S, F, Item>
+    where
+      O: Observer<OutputItem, Err>,
+      S: Observable<
+        Item,
+        Err,
+        CombineLatestManyObserver<
+          $rc<CombineLatestManyState<O, Item, F, OutputItem, Err>>,
+        >,
+      >,
+      F: FnMut(&[Item]) -> OutputItem,
+    {
+      type Unsub = ManySubscription<S::Unsub>;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let Self { sources, combine, .. } = self;
+        let len = sources.len();
+        let state = $rc::own(CombineLatestManyState {
+          downstream: Downstream::new(observer),
+          pending: (0..len).map(|_| None).collect(),
+          latest: vec![],
+          combine,
+          sources: len,
+          completed: 0,
+        });
+        if len == 0 && state.rc_deref_mut().downstream.terminate(Ok(())) {
+          drain(&state, |state| &mut state.downstream);
+        }
+        let unsubs = sources
+          .into_iter()
+          .enumerate()
+          .map(|(index, source)| {
+            source.actual_subscribe(CombineLatestManyObserver {
+              state: state.clone(),
+              index,
+            })
+          })
+          .collect();
+        ManySubscription(unsubs)
+      }
+    }
+
+    impl<Item, OutputItem, Err, S, F> ObservableExt<OutputItem, Err>
+      for $name<S, F, Item>
+    where
+      S: ObservableExt<Item, Err>,
+      F: FnMut(&[Item]) -> OutputItem,
+    {
+    }
+
+    impl<Item, OutputItem, Err, O, F> Observer<Item, Err>
+      for CombineLatestManyObserver<
+        $rc<CombineLatestManyState<O, Item, F, OutputItem, Err>>,
+      >
+    where
+      O: Observer<OutputItem, Err>,
+      F: FnMut(&[Item]) -> OutputItem,
+    {
+      fn next(&mut self, value: Item) {
+        let mut state = self.state.rc_deref_mut();
+        let CombineLatestManyState {
+          downstream, pending, latest, combine, ..
+        } = &mut *state;
+        if pending.is_empty() {
+          latest[self.index] = value;
+        } else {
+          pending[self.index] = Some(value);
+          if pending.iter().all(Option::is_some) {
+            *latest = pending.drain(..).flatten().collect();
+          }
+        }
+        if pending.is_empty()
+          && !downstream.is_closed()
+          && downstream.push(combine(latest))
+        {
+          drop(state);
+          drain(&self.state, |state| &mut state.downstream);
+        }
+      }
+
+      fn error(self, err: Err) {
+        let need_drain =
+          self.state.rc_deref_mut().downstream.terminate(Err(err));
+        if need_drain {
+          drain(&self.state, |state| &mut state.downstream);
+        }
+      }
+
+      fn complete(self) {
+        let mut state = self.state.rc_deref_mut();
+        state.completed += 1;
+        // a source completed without any value, no combined value can form.
+        let never_emitted =
+          !state.pending.is_empty() && state.pending[self.index].is_none();
+        if (never_emitted || state.completed == state.sources)
+          && state.downstream.terminate(Ok(()))
+        {
+          drop(state);
+          drain(&self.state, |state| &mut state.downstream);
+        }
+      }
+
+      fn is_finished(&self) -> bool {
+        self.state.rc_deref().downstream.is_finished()
+      }
+    }
+  };
+}
+
+impl_combine_latest_many_op!(CombineLatestManyOp, MutRc);
+impl_combine_latest_many_op!(CombineLatestManyOpThreads, MutArc);
+
 #[cfg(test)]
 mod tests {
   use std::cell::RefCell;
+  use std::convert::Infallible;
   use std::rc::Rc;
   use std::time::Duration;
 
@@ -279,4 +726,276 @@ mod tests {
     }
     assert!(complete);
   }
+
+  #[test]
+  fn complete_on_empty_side() {
+    let completed = Rc::new(RefCell::new(0));
+    let c_completed = completed.clone();
+    let mut live = Subject::<i32, ()>::default();
+    let empty = observable::create(|s: Subscriber<_>| s.complete());
+    live
+      .clone()
+      .combine_latest(empty, |a, b: i32| (a, b))
+      .on_complete(move || *c_completed.borrow_mut() += 1)
+      .on_error(|_| {})
+      .subscribe(|_| unreachable!());
+    assert_eq!(*completed.borrow(), 1);
+    live.next(1);
+
+    // the other side already emitted, but the empty side completes later.
+    let c_completed = completed.clone();
+    let mut a = Subject::<i32, ()>::default();
+    let b = Subject::<i32, ()>::default();
+    a.clone()
+      .combine_latest(b.clone(), |a, b| (a, b))
+      .on_complete(move || *c_completed.borrow_mut() += 1)
+      .on_error(|_| {})
+      .subscribe(|_| unreachable!());
+    a.next(1);
+    b.complete();
+    assert_eq!(*completed.borrow(), 2);
+    a.next(2);
+  }
+
+  #[test]
+  fn not_complete_while_other_side_can_emit() {
+    let mut values = vec![];
+    let mut completed = false;
+    let mut a = Subject::<i32, ()>::default();
+    let mut b = Subject::<i32, ()>::default();
+    a.clone()
+      .combine_latest(b.clone(), |a, b| (a, b))
+      .on_complete(|| completed = true)
+      .on_error(|_| {})
+      .subscribe(|v| values.push(v));
+
+    a.next(1);
+    a.clone().complete();
+    b.next(2);
+    b.clone().complete();
+    drop((a, b));
+
+    assert_eq!(values, vec![(1, 2)]);
+    assert!(completed);
+  }
+
+  #[test]
+  fn combine_many_complete_on_empty_source() {
+    let completed = Rc::new(RefCell::new(false));
+    let c_completed = completed.clone();
+    let mut a = Subject::<i32, ()>::default();
+    let b = Subject::<i32, ()>::default();
+    observable::combine_latest_many(
+      [a.clone(), b.clone()],
+      |latest: &[i32]| latest.iter().sum::<i32>(),
+    )
+    .on_complete(move || *c_completed.borrow_mut() = true)
+    .on_error(|_| {})
+    .subscribe(|_| unreachable!());
+
+    a.next(1);
+    b.complete();
+    assert!(*completed.borrow());
+  }
+
+  #[test]
+  fn combine_many_by_slice() {
+    let mut sums = vec![];
+    let completed = Rc::new(RefCell::new(false));
+    let c_completed = completed.clone();
+    let mut a = Subject::default();
+    let mut b = Subject::default();
+    let mut c = Subject::default();
+    observable::combine_latest_many(
+      [a.clone(), b.clone(), c.clone()],
+      |latest: &[i32]| latest.iter().sum::<i32>(),
+    )
+    .on_complete(move || *c_completed.borrow_mut() = true)
+    .subscribe(|v| sums.push(v));
+
+    a.next(1);
+    b.next(2);
+    a.next(3);
+    c.next(4);
+    b.next(5);
+    a.complete();
+    b.complete();
+    assert!(!*completed.borrow());
+    c.complete();
+
+    assert_eq!(sums, vec![9, 12]);
+    assert!(*completed.borrow());
+  }
+
+  #[test]
+  fn combine_many_reentrant() {
+    let sums = Rc::new(RefCell::new(vec![]));
+    let c_sums = sums.clone();
+    let mut a = Subject::<i32, Infallible>::default();
+    let mut b = Subject::default();
+    let mut c_a = a.clone();
+    observable::combine_latest_many(vec![a.clone(), b.clone()], |latest| {
+      latest.iter().sum::<i32>()
+    })
+    .subscribe(move |v| {
+      c_sums.borrow_mut().push(v);
+      // the downstream pushes into a source while it's notified.
+      if v == 3 {
+        c_a.next(10);
+      }
+    });
+
+    a.next(1);
+    b.next(2);
+    assert_eq!(*sums.borrow(), vec![3, 12]);
+  }
+
+  #[test]
+  fn combine_many_threads() {
+    let sums = MutArc::own(vec![]);
+    let c_sums = sums.clone();
+    let mut a = SubjectThreads::default();
+    let mut b = SubjectThreads::default();
+    observable::combine_latest_many_threads(
+      vec![a.clone(), b.clone()],
+      |latest: &[i32]| latest.iter().product::<i32>(),
+    )
+    .subscribe(move |v| c_sums.rc_deref_mut().push(v));
+
+    a.next(2);
+    b.next(3);
+    a.next(4);
+    assert_eq!(*sums.rc_deref(), vec![6, 12]);
+  }
+
+  #[test]
+  fn combine_no_source() {
+    let mut completed = false;
+    observable::combine_latest_many(
+      Vec::<Subject<i32, ()>>::new(),
+      |latest: &[i32]| latest.len(),
+    )
+    .on_complete(|| completed = true)
+    .on_error(|_| {})
+    .subscribe(|_| {});
+    assert!(completed);
+  }
+
+  #[test]
+  fn combine_latest_options_emit_partial() {
+    let mut values = vec![];
+    let mut completed = false;
+    let mut a = Subject::<i32, Infallible>::default();
+    let mut b = Subject::<&str, Infallible>::default();
+
+    a.clone()
+      .combine_latest_options(b.clone())
+      .on_complete(|| completed = true)
+      .subscribe(|v| values.push(v));
+
+    a.next(1);
+    a.next(2);
+    b.next("x");
+    a.clone().complete();
+    b.next("y");
+    b.complete();
+    drop(a);
+
+    assert_eq!(
+      values,
+      vec![
+        (Some(1), None),
+        (Some(2), None),
+        (Some(2), Some("x")),
+        (Some(2), Some("y")),
+      ]
+    );
+    assert!(completed);
+  }
+
+  #[test]
+  fn combine_latest_options_synchronous_sources() {
+    let mut values = vec![];
+    observable::from_iter([1, 2])
+      .combine_latest_options::<_, ()>(observable::empty())
+      .subscribe(|v: (_, Option<()>)| values.push(v));
+
+    assert_eq!(values, vec![(Some(1), None), (Some(2), None)]);
+  }
+
+  #[test]
+  fn combine_latest_options_reentrant() {
+    let values = Rc::new(RefCell::new(vec![]));
+    let c_values = values.clone();
+    let completed = Rc::new(RefCell::new(false));
+    let c_completed = completed.clone();
+    let mut a = Subject::<i32, Infallible>::default();
+    let b = Subject::<i32, Infallible>::default();
+    let mut c_b = b.clone();
+
+    a.clone()
+      .combine_latest_options(b.clone())
+      .on_complete(move || *c_completed.borrow_mut() = true)
+      .subscribe(move |v| {
+        c_values.borrow_mut().push(v);
+        // the downstream pushes into a source while it's notified.
+        if c_values.borrow().len() == 1 {
+          c_b.next(10);
+          c_b.clone().complete();
+        }
+      });
+
+    a.next(1);
+    a.complete();
+
+    assert_eq!(*values.borrow(), vec![(Some(1), None), (Some(1), Some(10))]);
+    assert!(*completed.borrow());
+  }
+
+  #[test]
+  fn combine_latest_options_threads_reentrant() {
+    use crate::rc::MutArc;
+
+    let values = MutArc::own(vec![]);
+    let c_values = values.clone();
+    let mut a = SubjectThreads::<i32, Infallible>::default();
+    let b = SubjectThreads::<i32, Infallible>::default();
+    let mut c_b = b.clone();
+
+    a.clone()
+      .combine_latest_options_threads(b.clone())
+      .subscribe(move |v| {
+        let first = {
+          let mut values = c_values.rc_deref_mut();
+          values.push(v);
+          values.len() == 1
+        };
+        if first {
+          c_b.next(10);
+        }
+      });
+
+    a.next(1);
+
+    assert_eq!(
+      *values.rc_deref(),
+      vec![(Some(1), None), (Some(1), Some(10))]
+    );
+  }
+
+  #[test]
+  fn combine_latest_options_threads() {
+    use crate::rc::MutArc;
+
+    let values = MutArc::own(vec![]);
+    let c_values = values.clone();
+    observable::of(1)
+      .combine_latest_options_threads(observable::of("a"))
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    assert_eq!(
+      *values.rc_deref(),
+      vec![(Some(1), None), (Some(1), Some("a"))]
+    );
+  }
 }
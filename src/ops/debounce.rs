@@ -80,12 +80,13 @@ where
     *self.task_handler.rc_deref_mut() = Some(handler);
   }
 
-  #[inline]
   fn error(self, err: Err) {
+    self.task_handler.clone().unsubscribe();
     self.observer.error(err);
   }
 
   fn complete(mut self) {
+    self.task_handler.clone().unsubscribe();
     if let Some(value) = self.trailing_value.rc_deref_mut().take() {
       self.observer.next(value);
     }
@@ -144,4 +145,25 @@ mod tests {
     sub.unsubscribe();
     assert_eq!(&*x.rc_deref(), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
   }
+
+  #[test]
+  fn flush_pending_on_complete() {
+    let x = MutRc::own(vec![]);
+    let c_x = x.clone();
+    let mut pool = LocalPool::new();
+    let mut subject = Subject::default();
+    subject
+      .clone()
+      .debounce(Duration::from_secs(3600), pool.spawner())
+      .subscribe(move |v| c_x.rc_deref_mut().push(v));
+
+    subject.next(1);
+    subject.next(2);
+    subject.next(3);
+    assert!(x.rc_deref().is_empty());
+    subject.complete();
+    pool.run();
+
+    assert_eq!(&*x.rc_deref(), &[3]);
+  }
 }
@@ -192,13 +192,13 @@ mod tests {
     let value = Rc::new(RefCell::new(0));
     let c_value = value.clone();
     let mut pool = LocalPool::new();
+    let stamp = Instant::now();
     observable::of(1)
       .delay(Duration::from_millis(1), pool.spawner())
       .subscribe(move |v| {
         *c_value.borrow_mut() = v;
       });
     assert_eq!(*value.borrow(), 0);
-    let stamp = Instant::now();
     pool.run();
     assert!(stamp.elapsed() >= Duration::from_millis(1));
     assert_eq!(*value.borrow(), 1);
@@ -252,4 +252,29 @@ mod tests {
     );
     assert!(accept_stamp.rc_deref().elapsed() < Duration::from_millis(1));
   }
+
+  #[test]
+  fn preserve_emission_spacing() {
+    let stamps = MutRc::own(vec![]);
+    let c_stamps = stamps.clone();
+    let mut pool = LocalPool::new();
+
+    let mut subject = Subject::default();
+    subject
+      .clone()
+      .delay(Duration::from_millis(100), pool.spawner())
+      .subscribe(move |_| c_stamps.rc_deref_mut().push(Instant::now()));
+
+    // the pool doesn't run in the meantime, the delay still counts from the
+    // original emission. Timers starting when the pool runs would fire both
+    // emissions together, the margin leaves room for a late first timer.
+    subject.next(());
+    std::thread::sleep(Duration::from_millis(60));
+    subject.next(());
+    pool.run();
+
+    let stamps = stamps.rc_deref();
+    assert_eq!(stamps.len(), 2);
+    assert!(stamps[1].duration_since(stamps[0]) >= Duration::from_millis(30));
+  }
 }
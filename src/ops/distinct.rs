@@ -1,5 +1,12 @@
-use crate::prelude::*;
-use std::{collections::HashSet, hash::Hash};
+use crate::{
+  prelude::*,
+  rc::{MutArc, RcDerefMut},
+};
+use std::{
+  collections::{HashMap, HashSet},
+  hash::Hash,
+  time::Duration,
+};
 
 #[derive(Clone)]
 pub struct DistinctOp<S> {
@@ -180,6 +187,66 @@ where
   }
 }
 
+#[derive(Clone)]
+pub struct ChangedCountOp<S> {
+  pub(crate) source: S,
+}
+
+impl<Item, Err, O, S> Observable<(usize, Item), Err, O> for ChangedCountOp<S>
+where
+  S: Observable<
+    Item,
+    Err,
+    DistinctUntilChangedObserver<ChangedCountObserver<O>, Item>,
+  >,
+  O: Observer<(usize, Item), Err>,
+  Item: PartialEq + Clone,
+{
+  type Unsub = S::Unsub;
+
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    self.source.actual_subscribe(DistinctUntilChangedObserver {
+      observer: ChangedCountObserver { observer, count: 0 },
+      last: None,
+    })
+  }
+}
+
+impl<Item, Err, S> ObservableExt<(usize, Item), Err> for ChangedCountOp<S> where
+  S: ObservableExt<Item, Err>
+{
+}
+
+pub struct ChangedCountObserver<O> {
+  observer: O,
+  count: usize,
+}
+
+impl<O, Item, Err> Observer<Item, Err> for ChangedCountObserver<O>
+where
+  O: Observer<(usize, Item), Err>,
+{
+  fn next(&mut self, value: Item) {
+    self.count += 1;
+    self.observer.next((self.count, value));
+  }
+
+  #[inline]
+  fn error(self, err: Err) {
+    self.observer.error(err)
+  }
+
+  #[inline]
+  fn complete(self) {
+    self.observer.complete()
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.observer.is_finished()
+  }
+}
+
 #[derive(Clone)]
 pub struct DistinctUntilKeyChangedOp<S, F> {
   pub(crate) source: S,
@@ -230,7 +297,7 @@ where
 {
   fn next(&mut self, value: Item) {
     let last = self.last.as_ref();
-    if last.map_or(true, |last| (self.key)(last) != (self.key)(&value)) {
+    if last.is_none_or(|last| (self.key)(last) != (self.key)(&value)) {
       self.last = Some(value.clone());
       self.observer.next(value);
     }
@@ -252,10 +319,98 @@ where
   }
 }
 
+#[derive(Clone)]
+pub struct DistinctTtlOp<S, SD> {
+  pub(crate) source: S,
+  pub(crate) ttl: Duration,
+  pub(crate) scheduler: SD,
+}
+
+/// The seen values, with the task that forgets each of them.
+type SeenWithTtl<Item> = MutArc<HashMap<Item, TaskHandle<NormalReturn<()>>>>;
+type ForgetTask<Item> = OnceTask<(SeenWithTtl<Item>, Item), NormalReturn<()>>;
+
+impl<Item, Err, O, S, SD> Observable<Item, Err, O> for DistinctTtlOp<S, SD>
+where
+  S: Observable<Item, Err, DistinctTtlObserver<O, Item, SD>>,
+  O: Observer<Item, Err>,
+  Item: Eq + Hash + Clone,
+  SD: Scheduler<ForgetTask<Item>>,
+{
+  type Unsub = S::Unsub;
+
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    self.source.actual_subscribe(DistinctTtlObserver {
+      observer,
+      seen: MutArc::own(HashMap::new()),
+      ttl: self.ttl,
+      scheduler: self.scheduler,
+    })
+  }
+}
+
+impl<Item, Err, S, SD> ObservableExt<Item, Err> for DistinctTtlOp<S, SD> where
+  S: ObservableExt<Item, Err>
+{
+}
+
+pub struct DistinctTtlObserver<O, Item, SD> {
+  observer: O,
+  seen: SeenWithTtl<Item>,
+  ttl: Duration,
+  scheduler: SD,
+}
+
+fn forget_task<Item: Eq + Hash>(
+  (seen, value): (SeenWithTtl<Item>, Item),
+) -> NormalReturn<()> {
+  seen.rc_deref_mut().remove(&value);
+  NormalReturn::new(())
+}
+
+impl<O, Item, SD> DistinctTtlObserver<O, Item, SD> {
+  fn forget_all(&self) {
+    let seen = std::mem::take(&mut *self.seen.rc_deref_mut());
+    seen.into_values().for_each(|handle| handle.unsubscribe());
+  }
+}
+
+impl<O, Item, Err, SD> Observer<Item, Err> for DistinctTtlObserver<O, Item, SD>
+where
+  O: Observer<Item, Err>,
+  Item: Hash + Eq + Clone,
+  SD: Scheduler<ForgetTask<Item>>,
+{
+  fn next(&mut self, value: Item) {
+    if self.seen.rc_deref_mut().contains_key(&value) {
+      return;
+    }
+    let task = OnceTask::new(forget_task, (self.seen.clone(), value.clone()));
+    let handle = self.scheduler.schedule(task, Some(self.ttl));
+    self.seen.rc_deref_mut().insert(value.clone(), handle);
+    self.observer.next(value);
+  }
+
+  fn error(self, err: Err) {
+    self.forget_all();
+    self.observer.error(err)
+  }
+
+  fn complete(self) {
+    self.forget_all();
+    self.observer.complete()
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.observer.is_finished()
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
-  use std::{cell::RefCell, rc::Rc};
+  use std::{cell::RefCell, convert::Infallible, rc::Rc};
 
   #[test]
   fn smoke() {
@@ -303,6 +458,16 @@ mod tests {
     b.iter(smoke);
   }
 
+  #[test]
+  fn changed_count() {
+    let mut changes = vec![];
+    observable::from_iter([1, 1, 2, 2, 2, 1, 3, 3])
+      .changed_count()
+      .subscribe(|v| changes.push(v));
+
+    assert_eq!(changes, vec![(1, 1), (2, 2), (3, 1), (4, 3)]);
+  }
+
   #[test]
   fn distinct_until_key_changed() {
     let x = Rc::new(RefCell::new(vec![]));
@@ -324,4 +489,47 @@ mod tests {
 
     assert_eq!(&*x_c.borrow(), &[(1, 2), (2, 2), (3, 2)]);
   }
+
+  #[test]
+  fn distinct_ttl() {
+    use crate::observable::fake_timer::FakeClock;
+
+    let x = Rc::new(RefCell::new(vec![]));
+    let x_c = x.clone();
+    let clock = FakeClock::default();
+    let mut subject = Subject::<_, Infallible>::default();
+    subject
+      .clone()
+      .distinct_ttl(Duration::from_millis(100), clock.clone())
+      .subscribe(move |v| x.borrow_mut().push(v));
+
+    subject.next(1);
+    subject.next(2);
+    subject.next(1);
+    clock.advance(Duration::from_millis(50));
+    subject.next(1);
+    assert_eq!(&*x_c.borrow(), &[1, 2]);
+
+    // both values are forgotten once their ttl elapsed.
+    clock.advance(Duration::from_millis(51));
+    subject.next(2);
+    subject.next(1);
+    subject.next(2);
+    assert_eq!(&*x_c.borrow(), &[1, 2, 2, 1]);
+  }
+
+  #[test]
+  fn distinct_ttl_complete() {
+    use crate::observable::fake_timer::FakeClock;
+
+    let mut values = vec![];
+    let mut completed = false;
+    observable::from_iter([1, 1, 2, 1])
+      .distinct_ttl(Duration::from_secs(3600), FakeClock::default())
+      .on_complete(|| completed = true)
+      .subscribe(|v| values.push(v));
+
+    assert_eq!(values, vec![1, 2]);
+    assert!(completed);
+  }
 }
@@ -0,0 +1,117 @@
+use crate::prelude::*;
+
+/// Emits the items of every `Vec` emitted by the source, one by one. See
+/// [`ObservableExt::explode`].
+#[derive(Clone)]
+pub struct ExplodeOp<S> {
+  source: S,
+}
+
+impl<S> ExplodeOp<S> {
+  #[inline]
+  pub fn new(source: S) -> Self {
+    Self { source }
+  }
+}
+
+impl<Item, Err, O, S> Observable<Item, Err, O> for ExplodeOp<S>
+where
+  S: Observable<Vec<Item>, Err, ExplodeObserver<O>>,
+  O: Observer<Item, Err>,
+{
+  type Unsub = S::Unsub;
+
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    self.source.actual_subscribe(ExplodeObserver { observer })
+  }
+}
+
+impl<Item, Err, S> ObservableExt<Item, Err> for ExplodeOp<S> where
+  S: ObservableExt<Vec<Item>, Err>
+{
+}
+
+pub struct ExplodeObserver<O> {
+  observer: O,
+}
+
+impl<Item, Err, O> Observer<Vec<Item>, Err> for ExplodeObserver<O>
+where
+  O: Observer<Item, Err>,
+{
+  fn next(&mut self, values: Vec<Item>) {
+    for v in values {
+      if self.observer.is_finished() {
+        break;
+      }
+      self.observer.next(v);
+    }
+  }
+
+  #[inline]
+  fn error(self, err: Err) {
+    self.observer.error(err)
+  }
+
+  #[inline]
+  fn complete(self) {
+    self.observer.complete()
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.observer.is_finished()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+
+  #[test]
+  fn inverse_of_buffer() {
+    let mut values = vec![];
+    let mut completed = false;
+    observable::from_iter(0..7)
+      .buffer_with_count(3)
+      .explode()
+      .on_complete(|| completed = true)
+      .subscribe(|v| values.push(v));
+
+    assert_eq!(values, (0..7).collect::<Vec<_>>());
+    assert!(completed);
+  }
+
+  #[test]
+  fn skip_empty_vec() {
+    let mut values = vec![];
+    observable::from_iter([vec![1], vec![], vec![2, 3]])
+      .explode()
+      .subscribe(|v| values.push(v));
+
+    assert_eq!(values, vec![1, 2, 3]);
+  }
+
+  #[test]
+  fn stop_in_the_middle_of_vec() {
+    let mut values = vec![];
+    observable::of(vec![1, 2, 3, 4])
+      .explode()
+      .take(2)
+      .subscribe(|v| values.push(v));
+
+    assert_eq!(values, vec![1, 2]);
+  }
+
+  #[test]
+  fn forward_error() {
+    let mut error = None;
+    observable::throw("oops")
+      .map(|_| vec![1])
+      .explode()
+      .on_error(|e| error = Some(e))
+      .subscribe(|_: i32| {});
+
+    assert_eq!(error, Some("oops"));
+  }
+}
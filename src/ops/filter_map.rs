@@ -93,6 +93,36 @@ mod test {
       .subscribe(|_| {});
   }
 
+  #[test]
+  fn parse_and_filter() {
+    let mut values = vec![];
+    observable::from_iter(vec![
+      "1".to_string(),
+      "two".to_string(),
+      "3".to_string(),
+    ])
+    .filter_map(|l| l.parse::<i32>().ok())
+    .subscribe(|v| values.push(v));
+    assert_eq!(values, vec![1, 3]);
+  }
+
+  #[cfg(not(target_arch = "wasm32"))]
+  #[test]
+  fn filter_map_threads() {
+    use crate::rc::{MutArc, RcDeref, RcDerefMut};
+
+    let values = MutArc::own(vec![]);
+    let c_values = values.clone();
+    let mut subject = SubjectThreads::default();
+    subject
+      .clone()
+      .filter_map(|v: i32| (v % 2 == 0).then_some(v * 10))
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    (0..5).for_each(|v| subject.next(v));
+    assert_eq!(*values.rc_deref(), vec![0, 20, 40]);
+  }
+
   #[test]
   fn bench() {
     do_bench();
@@ -3,6 +3,15 @@ use crate::{
   rc::{MutArc, MutRc, RcDerefMut},
 };
 
+/// Why a stream finalized, passed to the action of
+/// [`ObservableExt::finalize_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminationReason {
+  Complete,
+  Error,
+  Unsubscribe,
+}
+
 #[derive(Clone)]
 pub struct FinalizeOp<S, F> {
   source: S,
@@ -15,8 +24,45 @@ pub struct FinalizeOpThreads<S, F> {
   func: F,
 }
 
+#[derive(Clone)]
+pub struct FinalizeWithOp<S, F> {
+  source: S,
+  func: F,
+}
+
+#[derive(Clone)]
+pub struct FinalizeWithOpThreads<S, F> {
+  source: S,
+  func: F,
+}
+
+/// The action run once when a stream finalizes.
+pub trait Finalizer {
+  fn finalize(self, reason: TerminationReason);
+}
+
+/// A finalizer ignoring the termination reason.
+pub struct NoReason<F>(F);
+
+/// A finalizer receiving the termination reason.
+pub struct WithReason<F>(F);
+
+impl<F: FnOnce()> Finalizer for NoReason<F> {
+  #[inline]
+  fn finalize(self, _: TerminationReason) {
+    (self.0)()
+  }
+}
+
+impl<F: FnOnce(TerminationReason)> Finalizer for WithReason<F> {
+  #[inline]
+  fn finalize(self, reason: TerminationReason) {
+    (self.0)(reason)
+  }
+}
+
 macro_rules! impl_finalize_op {
-  ($name: ident, $rc:ident) => {
+  ($name: ident, $rc:ident, $finalizer: ident, $($fn_bound: tt)+) => {
     impl<S, F> $name<S, F> {
       #[inline]
       pub fn new(source: S, func: F) -> Self {
@@ -27,13 +73,13 @@ macro_rules! impl_finalize_op {
     impl<Item, Err, O, S, F> Observable<Item, Err, O> for $name<S, F>
     where
       O: Observer<Item, Err>,
-      S: Observable<Item, Err, FinalizerObserver<O, $rc<Option<F>>>>,
-      F: FnOnce(),
+      S: Observable<Item, Err, FinalizerObserver<O, $rc<Option<$finalizer<F>>>>>,
+      F: $($fn_bound)+,
     {
-      type Unsub = FinalizerSubscription<S::Unsub, $rc<Option<F>>>;
+      type Unsub = FinalizerSubscription<S::Unsub, $rc<Option<$finalizer<F>>>>;
 
       fn actual_subscribe(self, observer: O) -> Self::Unsub {
-        let func = $rc::own(Some(self.func));
+        let func = $rc::own(Some($finalizer(self.func)));
         let subscription = self
           .source
           .actual_subscribe(FinalizerObserver { observer, func: func.clone() });
@@ -48,18 +94,37 @@ macro_rules! impl_finalize_op {
   };
 }
 
-impl_finalize_op!(FinalizeOp, MutRc);
-impl_finalize_op!(FinalizeOpThreads, MutArc);
+impl_finalize_op!(FinalizeOp, MutRc, NoReason, FnOnce());
+impl_finalize_op!(FinalizeOpThreads, MutArc, NoReason, FnOnce());
+impl_finalize_op!(FinalizeWithOp, MutRc, WithReason, FnOnce(TerminationReason));
+impl_finalize_op!(
+  FinalizeWithOpThreads,
+  MutArc,
+  WithReason,
+  FnOnce(TerminationReason)
+);
+
 pub struct FinalizerObserver<O, F> {
   observer: O,
   func: F,
 }
 
+fn finalize<C, F>(func: &C, reason: TerminationReason)
+where
+  C: RcDerefMut<Target = Option<F>>,
+  F: Finalizer,
+{
+  let func = func.rc_deref_mut().take();
+  if let Some(func) = func {
+    func.finalize(reason)
+  }
+}
+
 impl<Item, Err, O, F, C> Observer<Item, Err> for FinalizerObserver<O, C>
 where
   C: RcDerefMut<Target = Option<F>>,
   O: Observer<Item, Err>,
-  F: FnOnce(),
+  F: Finalizer,
 {
   #[inline]
   fn next(&mut self, value: Item) {
@@ -68,16 +133,12 @@ where
 
   fn error(self, err: Err) {
     self.observer.error(err);
-    if let Some(func) = self.func.rc_deref_mut().take() {
-      func()
-    }
+    finalize(&self.func, TerminationReason::Error);
   }
 
   fn complete(self) {
     self.observer.complete();
-    if let Some(func) = self.func.rc_deref_mut().take() {
-      func()
-    }
+    finalize(&self.func, TerminationReason::Complete);
   }
 
   #[inline]
@@ -95,13 +156,11 @@ impl<C, F, U> Subscription for FinalizerSubscription<U, C>
 where
   U: Subscription,
   C: RcDerefMut<Target = Option<F>>,
-  F: FnOnce(),
+  F: Finalizer,
 {
   fn unsubscribe(self) {
     self.subscription.unsubscribe();
-    if let Some(func) = self.func.rc_deref_mut().take() {
-      func()
-    }
+    finalize(&self.func, TerminationReason::Unsubscribe);
   }
 
   #[inline]
@@ -112,8 +171,9 @@ where
 
 #[cfg(test)]
 mod test {
+  use super::TerminationReason;
   use crate::prelude::*;
-  use std::cell::Cell;
+  use std::cell::{Cell, RefCell};
   use std::rc::Rc;
   use std::sync::{
     atomic::{AtomicBool, Ordering},
@@ -239,6 +299,52 @@ mod test {
     assert!(finalized.load(Ordering::Relaxed));
   }
 
+  #[test]
+  fn finalize_with_reason() {
+    let reasons = Rc::new(RefCell::new(vec![]));
+    let finalize_with = |s: Subject<'static, i32, &'static str>| {
+      let reasons = reasons.clone();
+      s.finalize_with(move |reason| reasons.borrow_mut().push(reason))
+        .on_error(|_| {})
+        .subscribe(|_| {})
+    };
+
+    let s = Subject::default();
+    let subscription = finalize_with(s.clone());
+    s.complete();
+    subscription.unsubscribe();
+
+    let s = Subject::default();
+    let subscription = finalize_with(s.clone());
+    s.error("oops");
+    subscription.unsubscribe();
+
+    let subscription = finalize_with(Subject::default());
+    subscription.unsubscribe();
+
+    assert_eq!(
+      *reasons.borrow(),
+      vec![
+        TerminationReason::Complete,
+        TerminationReason::Error,
+        TerminationReason::Unsubscribe
+      ]
+    );
+  }
+
+  #[test]
+  fn finalize_with_threads() {
+    use std::sync::Mutex;
+
+    let reason = Arc::new(Mutex::new(None));
+    let c_reason = reason.clone();
+    observable::of(1)
+      .finalize_with_threads(move |r| *c_reason.lock().unwrap() = Some(r))
+      .subscribe(|_| {});
+
+    assert_eq!(*reason.lock().unwrap(), Some(TerminationReason::Complete));
+  }
+
   #[test]
   fn bench() {
     do_bench();
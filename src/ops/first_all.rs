@@ -0,0 +1,458 @@
+use crate::{
+  prelude::*,
+  rc::{MutArc, MutRc, RcDeref, RcDerefMut},
+};
+use std::collections::VecDeque;
+
+/// Subscribes to the inner observables emitted by the source one after
+/// another, emits the first value of each and unsubscribes it right after.
+/// See [`ObservableExt::map_async`].
+pub struct FirstAllOp<'a, S, ObservableItem> {
+  source: S,
+  _marker: TypeHint<&'a ObservableItem>,
+}
+
+/// The thread safe version of [`FirstAllOp`].
+pub struct FirstAllOpThreads<S, ObservableItem> {
+  source: S,
+  _marker: TypeHint<ObservableItem>,
+}
+
+impl<'a, S, ObservableItem> FirstAllOp<'a, S, ObservableItem> {
+  #[inline]
+  pub(crate) fn new(source: S) -> Self {
+    Self { source, _marker: TypeHint::default() }
+  }
+}
+
+impl<S, ObservableItem> FirstAllOpThreads<S, ObservableItem> {
+  #[inline]
+  pub(crate) fn new(source: S) -> Self {
+    Self { source, _marker: TypeHint::default() }
+  }
+}
+
+struct FirstAllData<O, Task> {
+  observer: O,
+  // the inner observables waiting for the running one to emit its value.
+  waiting: VecDeque<Task>,
+  // the id of the last subscribed inner observable.
+  active: usize,
+  inner_running: bool,
+  outside_completed: bool,
+}
+
+impl<O, Task> FirstAllData<O, Task> {
+  /// The running inner observable is done, returns the next one to subscribe
+  /// or completes the observer if there is none left.
+  fn inner_done<Item, Err>(data: &mut Option<Self>) -> Option<Task>
+  where
+    O: Observer<Item, Err>,
+  {
+    let inner = data.as_mut()?;
+    inner.inner_running = false;
+    let task = inner.waiting.pop_front();
+    if task.is_none() && inner.outside_completed {
+      data.take().unwrap().observer.complete();
+    }
+    task
+  }
+}
+
+/// The subscriptions of the inner observables of [`FirstAllOp`].
+pub struct InnerSubscriptions<U> {
+  running: Option<U>,
+  // an inner observable can't be unsubscribed while it's emitting its value,
+  // it's unsubscribed by the next event of the stream.
+  taken: Option<U>,
+}
+
+impl<U: Subscription> Subscription for InnerSubscriptions<U> {
+  fn unsubscribe(self) {
+    if let Some(running) = self.running {
+      running.unsubscribe();
+    }
+    if let Some(taken) = self.taken {
+      taken.unsubscribe();
+    }
+  }
+
+  fn is_closed(&self) -> bool {
+    self.running.as_ref().is_none_or(|u| u.is_closed())
+      && self.taken.as_ref().is_none_or(|u| u.is_closed())
+  }
+}
+
+fn release_taken<U, Subs>(subs: &Subs)
+where
+  U: Subscription,
+  Subs: RcDerefMut<Target = Option<InnerSubscriptions<U>>>,
+{
+  let taken = subs.rc_deref_mut().as_mut().and_then(|s| s.taken.take());
+  if let Some(taken) = taken {
+    taken.unsubscribe();
+  }
+}
+
+type DataLocal<'a, O> = MutRc<Option<FirstAllData<O, Box<dyn FnOnce() + 'a>>>>;
+type SubsLocal<'a> = MutRc<Option<InnerSubscriptions<BoxSubscription<'a>>>>;
+type DataThreads<O> = MutArc<Option<FirstAllData<O, Box<dyn FnOnce() + Send>>>>;
+type SubsThreads = MutArc<Option<InnerSubscriptions<BoxSubscriptionThreads>>>;
+
+pub struct OutsideObserver<'a, O, Item> {
+  data: DataLocal<'a, O>,
+  subs: SubsLocal<'a>,
+  _hint: TypeHint<Item>,
+}
+
+pub struct OutsideObserverThreads<O, Item> {
+  data: DataThreads<O>,
+  subs: SubsThreads,
+  _hint: TypeHint<Item>,
+}
+
+pub struct InnerObserver<'a, O> {
+  // released once the first value is taken.
+  data: Option<DataLocal<'a, O>>,
+  subs: SubsLocal<'a>,
+}
+
+pub struct InnerObserverThreads<O> {
+  data: Option<DataThreads<O>>,
+  subs: SubsThreads,
+}
+
+macro_rules! impl_first_all {
+  ($op: ty, $outside: ident, $inner: ident, $data: ty, $subs: ty,
+    $rc: ident, $box_unsub: ty, $($lf: lifetime)? $(,$send: ident)?) => {
+    impl<$($lf,)? ObservableItem, Item, Err, O, S> Observable<Item, Err, O>
+      for $op
+    where
+      O: Observer<Item, Err> $(+ $lf)? $(+ $send + 'static)?,
+      S: Observable<ObservableItem, Err, $outside<$($lf,)? O, Item>>,
+      ObservableItem: Observable<Item, Err, $inner<$($lf,)? O>>
+        $(+ $lf)? $(+ $send + 'static)?,
+      ObservableItem::Unsub: $($lf)? $($send + 'static)?,
+    {
+      type Unsub = ZipSubscription<$subs, S::Unsub>;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let data = FirstAllData {
+          observer,
+          waiting: VecDeque::new(),
+          active: 0,
+          inner_running: false,
+          outside_completed: false,
+        };
+        let subs = $rc::own(Some(InnerSubscriptions { running: None, taken: None }));
+        let outside = $outside {
+          data: $rc::own(Some(data)),
+          subs: subs.clone(),
+          _hint: TypeHint::new(),
+        };
+        let unsub = self.source.actual_subscribe(outside);
+        ZipSubscription::new(subs, unsub)
+      }
+    }
+
+    impl<$($lf,)? ObservableItem, Item, Err, S> ObservableExt<Item, Err>
+      for $op
+    where
+      S: ObservableExt<ObservableItem, Err>,
+      ObservableItem: ObservableExt<Item, Err>,
+    {
+    }
+
+    impl<$($lf,)? O, Item> $outside<$($lf,)? O, Item> {
+      fn subscribe_inner<Err, ObservableItem>(
+        value: ObservableItem,
+        data: $data,
+        subs: $subs,
+      ) where
+        O: Observer<Item, Err>,
+        ObservableItem: Observable<Item, Err, $inner<$($lf,)? O>>,
+        ObservableItem::Unsub: $($lf)? $($send + 'static)?,
+      {
+        let id = {
+          let mut data = data.rc_deref_mut();
+          let Some(data) = data.as_mut() else { return };
+          data.active += 1;
+          data.inner_running = true;
+          data.active
+        };
+        let inner = $inner { data: Some(data.clone()), subs: subs.clone() };
+        let unsub = <$box_unsub>::new(value.actual_subscribe(inner));
+        let running = data
+          .rc_deref()
+          .as_ref()
+          .is_some_and(|data| data.active == id && data.inner_running);
+        let mut subs = subs.rc_deref_mut();
+        match subs.as_mut() {
+          Some(subs) if running => subs.running = Some(unsub),
+          // the inner observable took its value while subscribing, or the
+          // stream is done.
+          _ => {
+            drop(subs);
+            unsub.unsubscribe();
+          }
+        }
+      }
+    }
+
+    impl<$($lf,)? ObservableItem, Item, Err, O> Observer<ObservableItem, Err>
+      for $outside<$($lf,)? O, Item>
+    where
+      O: Observer<Item, Err> $(+ $lf)? $(+ $send + 'static)?,
+      ObservableItem: Observable<Item, Err, $inner<$($lf,)? O>>
+        $(+ $lf)? $(+ $send + 'static)?,
+      ObservableItem::Unsub: $($lf)? $($send + 'static)?,
+    {
+      fn next(&mut self, value: ObservableItem) {
+        release_taken(&self.subs);
+        let mut data = self.data.rc_deref_mut();
+        let Some(inner) = data.as_mut() else { return };
+        if inner.inner_running {
+          let data = self.data.clone();
+          let subs = self.subs.clone();
+          inner
+            .waiting
+            .push_back(Box::new(move || Self::subscribe_inner(value, data, subs)));
+        } else {
+          drop(data);
+          Self::subscribe_inner(value, self.data.clone(), self.subs.clone());
+        }
+      }
+
+      fn error(self, err: Err) {
+        release_taken(&self.subs);
+        let data = self.data.rc_deref_mut().take();
+        if let Some(data) = data {
+          data.observer.error(err);
+        }
+      }
+
+      fn complete(self) {
+        release_taken(&self.subs);
+        let mut data = self.data.rc_deref_mut();
+        if let Some(inner) = data.as_mut() {
+          inner.outside_completed = true;
+          if !inner.inner_running {
+            data.take().unwrap().observer.complete();
+          }
+        }
+      }
+
+      fn is_finished(&self) -> bool {
+        self
+          .data
+          .rc_deref()
+          .as_ref()
+          .is_none_or(|data| data.observer.is_finished())
+      }
+    }
+
+    impl<$($lf,)? Item, Err, O> Observer<Item, Err> for $inner<$($lf,)? O>
+    where
+      O: Observer<Item, Err>,
+    {
+      fn next(&mut self, value: Item) {
+        let Some(data) = self.data.take() else { return };
+        let task = {
+          let mut data = data.rc_deref_mut();
+          if let Some(inner) = data.as_mut() {
+            inner.observer.next(value);
+          }
+          FirstAllData::inner_done(&mut data)
+        };
+        // keep the subscription of this inner observable until the next event
+        // of the stream, and release the one kept before.
+        let prev = self.subs.rc_deref_mut().as_mut().and_then(|subs| {
+          let running = subs.running.take()?;
+          subs.taken.replace(running)
+        });
+        if let Some(prev) = prev {
+          prev.unsubscribe();
+        }
+        if let Some(task) = task {
+          task();
+        }
+      }
+
+      fn error(mut self, err: Err) {
+        if let Some(data) = self.data.take() {
+          let data = data.rc_deref_mut().take();
+          if let Some(data) = data {
+            data.observer.error(err);
+          }
+        }
+      }
+
+      fn complete(mut self) {
+        let Some(data) = self.data.take() else { return };
+        if let Some(subs) = self.subs.rc_deref_mut().as_mut() {
+          subs.running = None;
+        }
+        let task = FirstAllData::inner_done(&mut data.rc_deref_mut());
+        if let Some(task) = task {
+          task();
+        }
+      }
+
+      fn is_finished(&self) -> bool {
+        self.data.as_ref().is_none_or(|data| {
+          data
+            .rc_deref()
+            .as_ref()
+            .is_none_or(|data| data.observer.is_finished())
+        })
+      }
+    }
+  };
+}
+
+impl_first_all!(
+  FirstAllOp<'a, S, ObservableItem>,
+  OutsideObserver,
+  InnerObserver,
+  DataLocal<'a, O>,
+  SubsLocal<'a>,
+  MutRc,
+  BoxSubscription<'a>,
+  'a
+);
+impl_first_all!(
+  FirstAllOpThreads<S, ObservableItem>,
+  OutsideObserverThreads,
+  InnerObserverThreads,
+  DataThreads<O>,
+  SubsThreads,
+  MutArc,
+  BoxSubscriptionThreads,
+  ,
+  Send
+);
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+
+  #[test]
+  fn map_async_preserve_order() {
+    use crate::rc::{MutRc, RcDeref, RcDerefMut};
+    use futures::executor::LocalPool;
+    use std::time::Duration;
+
+    let mut pool = LocalPool::new();
+    let spawner = pool.spawner();
+    let emitted = MutRc::own(vec![]);
+    let c_emitted = emitted.clone();
+    observable::from_iter([30, 10, 20])
+      .map_async(move |ms| {
+        observable::timer(ms, Duration::from_millis(ms), spawner.clone())
+      })
+      .subscribe(move |v| c_emitted.rc_deref_mut().push(v));
+    pool.run();
+
+    assert_eq!(*emitted.rc_deref(), vec![30, 10, 20]);
+  }
+
+  #[test]
+  fn map_async_take_first_value() {
+    let mut emitted = vec![];
+    let mut completed = false;
+    observable::from_iter([1, 2])
+      .map_async(|v| observable::from_iter([v, v * 10]))
+      .on_complete(|| completed = true)
+      .subscribe(|v| emitted.push(v));
+
+    assert_eq!(emitted, vec![1, 2]);
+    assert!(completed);
+  }
+
+  #[test]
+  fn map_async_inner_error() {
+    let mut error = None;
+    let mut source = Subject::default();
+    source
+      .clone()
+      .map_async(|v| {
+        observable::of_result(if v == 1 { Ok(v) } else { Err("oops") })
+      })
+      .on_error(|e| error = Some(e))
+      .subscribe(|_| {});
+
+    source.next(1);
+    source.next(2);
+    drop(source);
+    assert_eq!(error, Some("oops"));
+  }
+
+  #[test]
+  fn unsubscribe_inner_after_first_value() {
+    use crate::rc::{MutRc, RcDeref, RcDerefMut};
+
+    let mut source = Subject::default();
+    let mut inner = Subject::<i32, std::convert::Infallible>::default();
+    let inner_unsubscribed = MutRc::own(false);
+    let emitted = MutRc::own(vec![]);
+    let c_emitted = emitted.clone();
+    let c_inner = inner.clone();
+    let c_unsubscribed = inner_unsubscribed.clone();
+    source
+      .clone()
+      .map_async(move |_: i32| {
+        let unsubscribed = c_unsubscribed.clone();
+        c_inner
+          .clone()
+          .finalize(move || *unsubscribed.rc_deref_mut() = true)
+      })
+      .subscribe(move |v| c_emitted.rc_deref_mut().push(v));
+
+    source.next(1);
+    inner.next(10);
+    inner.next(20);
+    inner.retain();
+    assert!(inner.is_empty());
+    assert_eq!(*emitted.rc_deref(), vec![10]);
+
+    // the subscription is released once the emission of the inner returned.
+    source.next(2);
+    assert!(*inner_unsubscribed.rc_deref());
+    inner.next(30);
+    assert_eq!(*emitted.rc_deref(), vec![10, 30]);
+  }
+
+  #[test]
+  fn unsubscribe_synchronous_inner() {
+    use crate::rc::{MutRc, RcDeref, RcDerefMut};
+
+    let mut emitted = vec![];
+    let never = Subject::<i32, std::convert::Infallible>::default();
+    let unsubscribed = MutRc::own(0);
+    let c_unsubscribed = unsubscribed.clone();
+    observable::from_iter([1, 2, 3])
+      .map_async(move |v| {
+        let unsubscribed = c_unsubscribed.clone();
+        observable::of(v)
+          .merge(never.clone())
+          .finalize(move || *unsubscribed.rc_deref_mut() += 1)
+      })
+      .subscribe(|v| emitted.push(v));
+
+    assert_eq!(emitted, vec![1, 2, 3]);
+    assert_eq!(*unsubscribed.rc_deref(), 3);
+  }
+
+  #[test]
+  fn map_async_threads() {
+    use crate::rc::{MutArc, RcDeref, RcDerefMut};
+
+    let emitted = MutArc::own(vec![]);
+    let c_emitted = emitted.clone();
+    observable::from_iter([1, 2, 3])
+      .map_async_threads(|v| observable::from_iter([v * 2, 0]))
+      .subscribe(move |v| c_emitted.rc_deref_mut().push(v));
+
+    assert_eq!(*emitted.rc_deref(), vec![2, 4, 6]);
+  }
+}
@@ -0,0 +1,349 @@
+use crate::{
+  prelude::*,
+  rc::{MutArc, MutRc, RcDerefMut},
+};
+use std::time::Duration;
+
+/// Emits the first item of the source, or the fallback value if the source
+/// doesn't emit within the duration. See [`ObservableExt::first_or_timeout`].
+#[derive(Clone)]
+pub struct FirstOrTimeoutOp<'a, S, SD, Item> {
+  source: S,
+  duration: Duration,
+  fallback: Item,
+  scheduler: SD,
+  _hint: TypeHint<&'a ()>,
+}
+
+/// The thread safe version of [`FirstOrTimeoutOp`].
+#[derive(Clone)]
+pub struct FirstOrTimeoutOpThreads<S, SD, Item> {
+  source: S,
+  duration: Duration,
+  fallback: Item,
+  scheduler: SD,
+}
+
+type TimeoutArgs<O, Item, U> =
+  (MutRc<Option<O>>, MutRc<Option<Item>>, MutRc<Option<U>>);
+type TimeoutArgsThreads<O, Item, U> =
+  (MutArc<Option<O>>, MutArc<Option<Item>>, MutArc<Option<U>>);
+
+macro_rules! impl_first_or_timeout {
+  ($op: ty, $observer: ident, $rc: ident, $args: ident, $box_unsub: ident,
+    $($lf: lifetime)? $(,$send: ident)?) => {
+    impl<$($lf,)? S, SD, Item> $op {
+      #[inline]
+      pub(crate) fn new(
+        source: S,
+        duration: Duration,
+        fallback: Item,
+        scheduler: SD,
+      ) -> Self {
+        Self {
+          source,
+          duration,
+          fallback,
+          scheduler,
+          $(_hint: TypeHint::<&$lf ()>::default(),)?
+        }
+      }
+    }
+
+    impl<$($lf,)? Item, Err, O, S, SD> Observable<Item, Err, O> for $op
+    where
+      O: Observer<Item, Err>,
+      S: Observable<Item, Err, $observer<$($lf,)? O, Item, SD>>,
+      S::Unsub: $($lf)? $($send + 'static)?,
+      SD: Scheduler<
+        OnceTask<
+          $args<O, Item, $box_unsub$(<$lf>)?>,
+          NormalReturn<()>,
+        >,
+      >,
+      SD: Scheduler<
+        OnceTask<$rc<Option<$box_unsub$(<$lf>)?>>, NormalReturn<()>>,
+      >,
+    {
+      type Unsub = ZipSubscription<
+        $rc<Option<$box_unsub$(<$lf>)?>>,
+        $rc<Option<TaskHandle<NormalReturn<()>>>>,
+      >;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let Self { source, duration, fallback, scheduler, .. } = self;
+        let observer = $rc::own(Some(observer));
+        let fallback = $rc::own(Some(fallback));
+        let source_unsub = $rc::own(None);
+
+        let task = OnceTask::new(
+          timeout_task,
+          (observer.clone(), fallback.clone(), source_unsub.clone()),
+        );
+        let handler = scheduler.schedule(task, Some(duration));
+        let task_handler = $rc::own(Some(handler));
+
+        let unsub = source.actual_subscribe($observer {
+          observer,
+          fallback,
+          task_handler: task_handler.clone(),
+          source_unsub: source_unsub.clone(),
+          scheduler,
+        });
+        *source_unsub.rc_deref_mut() = Some($box_unsub::new(unsub));
+        if task_handler.is_closed() {
+          // the source resolved synchronously, or the timeout already fired.
+          source_unsub.clone().unsubscribe();
+        }
+        ZipSubscription::new(source_unsub, task_handler)
+      }
+    }
+
+    impl<$($lf,)? Item, Err, S, SD> ObservableExt<Item, Err> for $op
+    where
+      S: ObservableExt<Item, Err>,
+    {
+    }
+
+    impl<$($lf,)? O, Item, SD> $observer<$($lf,)? O, Item, SD>
+    where
+      SD: Scheduler<
+        OnceTask<$rc<Option<$box_unsub$(<$lf>)?>>, NormalReturn<()>>,
+      >,
+    {
+      fn resolve(&self) -> Option<O> {
+        self.task_handler.clone().unsubscribe();
+        // Resolved inside a notification of the source, which can't be
+        // unsubscribed until the notification returns.
+        let task = OnceTask::new(unsubscribe_task, self.source_unsub.clone());
+        self.scheduler.schedule(task, None);
+        self.fallback.rc_deref_mut().take();
+        self.observer.rc_deref_mut().take()
+      }
+    }
+
+    impl<$($lf,)? Item, Err, O, SD> Observer<Item, Err>
+      for $observer<$($lf,)? O, Item, SD>
+    where
+      O: Observer<Item, Err>,
+      SD: Scheduler<
+        OnceTask<$rc<Option<$box_unsub$(<$lf>)?>>, NormalReturn<()>>,
+      >,
+    {
+      fn next(&mut self, value: Item) {
+        if let Some(mut observer) = self.resolve() {
+          observer.next(value);
+          observer.complete();
+        }
+      }
+
+      fn error(self, err: Err) {
+        if let Some(observer) = self.resolve() {
+          observer.error(err);
+        }
+      }
+
+      fn complete(self) {
+        // An empty source has nothing better than the fallback to offer.
+        let fallback = self.fallback.rc_deref_mut().take();
+        if let Some(mut observer) = self.resolve() {
+          if let Some(fallback) = fallback {
+            observer.next(fallback);
+          }
+          observer.complete();
+        }
+      }
+
+      #[inline]
+      fn is_finished(&self) -> bool {
+        self.observer.is_finished()
+      }
+    }
+  };
+}
+
+impl_first_or_timeout!(
+  FirstOrTimeoutOp<'a, S, SD, Item>,
+  FirstOrTimeoutObserver,
+  MutRc,
+  TimeoutArgs,
+  BoxSubscription,
+  'a
+);
+impl_first_or_timeout!(
+  FirstOrTimeoutOpThreads<S, SD, Item>,
+  FirstOrTimeoutObserverThreads,
+  MutArc,
+  TimeoutArgsThreads,
+  BoxSubscriptionThreads,
+  ,
+  Send
+);
+
+fn timeout_task<Obs, Fallback, U, O, Item, Err>(
+  (observer, fallback, source_unsub): (Obs, Fallback, U),
+) -> NormalReturn<()>
+where
+  Obs: RcDerefMut<Target = Option<O>>,
+  Fallback: RcDerefMut<Target = Option<Item>>,
+  O: Observer<Item, Err>,
+  U: Subscription,
+{
+  let observer = observer.rc_deref_mut().take();
+  if let Some(mut observer) = observer {
+    let fallback = fallback.rc_deref_mut().take();
+    if let Some(fallback) = fallback {
+      observer.next(fallback);
+    }
+    observer.complete();
+  }
+  source_unsub.unsubscribe();
+  NormalReturn::new(())
+}
+
+fn unsubscribe_task<U: Subscription>(source_unsub: U) -> NormalReturn<()> {
+  source_unsub.unsubscribe();
+  NormalReturn::new(())
+}
+
+pub struct FirstOrTimeoutObserver<'a, O, Item, SD> {
+  observer: MutRc<Option<O>>,
+  fallback: MutRc<Option<Item>>,
+  task_handler: MutRc<Option<TaskHandle<NormalReturn<()>>>>,
+  source_unsub: MutRc<Option<BoxSubscription<'a>>>,
+  scheduler: SD,
+}
+
+pub struct FirstOrTimeoutObserverThreads<O, Item, SD> {
+  observer: MutArc<Option<O>>,
+  fallback: MutArc<Option<Item>>,
+  task_handler: MutArc<Option<TaskHandle<NormalReturn<()>>>>,
+  source_unsub: MutArc<Option<BoxSubscriptionThreads>>,
+  scheduler: SD,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::rc::{MutRc, RcDeref};
+  use futures::executor::LocalPool;
+  use std::convert::Infallible;
+
+  #[test]
+  fn emit_first_item_in_time() {
+    let mut pool = LocalPool::new();
+    let values = MutRc::own(vec![]);
+    let completed = MutRc::own(false);
+    let c_values = values.clone();
+    let c_completed = completed.clone();
+    observable::timer(1, Duration::from_millis(5), pool.spawner())
+      .merge(observable::timer(
+        2,
+        Duration::from_millis(10),
+        pool.spawner(),
+      ))
+      .first_or_timeout(Duration::from_millis(50), 0, pool.spawner())
+      .on_complete(move || *c_completed.rc_deref_mut() = true)
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    pool.run();
+    assert_eq!(*values.rc_deref(), vec![1]);
+    assert!(*completed.rc_deref());
+  }
+
+  #[test]
+  fn emit_fallback_on_timeout() {
+    let mut pool = LocalPool::new();
+    let values = MutRc::own(vec![]);
+    let c_values = values.clone();
+    let mut source = Subject::default();
+    source
+      .clone()
+      .first_or_timeout(Duration::from_millis(5), "cached", pool.spawner())
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    pool.run();
+    assert_eq!(*values.rc_deref(), vec!["cached"]);
+    // the source is unsubscribed once the fallback is emitted.
+    source.next("late");
+    source.retain();
+    assert!(source.is_empty());
+    assert_eq!(*values.rc_deref(), vec!["cached"]);
+  }
+
+  #[test]
+  fn emit_fallback_on_empty_source() {
+    let mut pool = LocalPool::new();
+    let values = MutRc::own(vec![]);
+    let c_values = values.clone();
+    observable::empty()
+      .first_or_timeout(Duration::from_secs(10), 0, pool.spawner())
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    assert_eq!(*values.rc_deref(), vec![0]);
+    pool.run_until_stalled();
+    assert_eq!(*values.rc_deref(), vec![0]);
+  }
+
+  #[test]
+  fn sync_first_item() {
+    let mut pool = LocalPool::new();
+    let values = MutRc::own(vec![]);
+    let c_values = values.clone();
+    let subscription = observable::from_iter([1, 2, 3])
+      .first_or_timeout(Duration::from_secs(10), 0, pool.spawner())
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    pool.run_until_stalled();
+    assert!(subscription.is_closed());
+    assert_eq!(*values.rc_deref(), vec![1]);
+  }
+
+  #[test]
+  fn unsubscribe_source_on_async_first_item() {
+    let mut pool = LocalPool::new();
+    let values = MutRc::own(vec![]);
+    let c_values = values.clone();
+    let unsubscribed = MutRc::own(false);
+    let c_unsubscribed = unsubscribed.clone();
+    let mut source = Subject::<_, Infallible>::default();
+    source
+      .clone()
+      .finalize(move || *c_unsubscribed.rc_deref_mut() = true)
+      .first_or_timeout(Duration::from_secs(10), 0, pool.spawner())
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    source.next(1);
+    pool.run_until_stalled();
+    assert!(*unsubscribed.rc_deref());
+    source.retain();
+    assert!(source.is_empty());
+    assert_eq!(*values.rc_deref(), vec![1]);
+  }
+
+  #[test]
+  fn first_or_timeout_threads() {
+    use futures::executor::ThreadPool;
+    use std::sync::{mpsc, Mutex};
+
+    let pool = ThreadPool::new().unwrap();
+    let values = MutArc::own(vec![]);
+    let c_values = values.clone();
+    let (unsubscribed, wait_unsubscribed) = mpsc::channel();
+    let unsubscribed = Mutex::new(unsubscribed);
+    let mut source = SubjectThreads::<_, Infallible>::default();
+    source
+      .clone()
+      .finalize_threads(move || {
+        let _ = unsubscribed.lock().unwrap().send(());
+      })
+      .first_or_timeout_threads(Duration::from_secs(10), 0, pool)
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    source.next(1);
+    wait_unsubscribed.recv().unwrap();
+    source.retain();
+    assert!(source.is_empty());
+    assert_eq!(*values.rc_deref(), vec![1]);
+  }
+}
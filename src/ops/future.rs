@@ -5,7 +5,10 @@ use std::{
 };
 
 use futures::{
-  channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
+  channel::{
+    mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
+    oneshot,
+  },
   ready, Future, FutureExt, StreamExt,
 };
 
@@ -124,11 +127,96 @@ fn send_observable_value<T, E>(
   }
 }
 
+/// The error of a [`SubscriptionFuture`], tells apart a subscription that
+/// ended before the stream terminated from an error emitted by the stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubscriptionFutureError<E> {
+  /// The subscription was unsubscribed, or its observer dropped, before the
+  /// stream completed or errored.
+  Cancelled,
+  /// The observable emitted an error.
+  Error(E),
+}
+
+/// A future that resolves when the subscription it comes with terminates,
+/// returned by [`ObservableExt::subscribe_future`].
+///
+/// [`ObservableExt::subscribe_future`]: crate::observable::ObservableExt::subscribe_future
+pub struct SubscriptionFuture<E> {
+  receiver: oneshot::Receiver<Result<(), E>>,
+}
+
+impl<E> Future for SubscriptionFuture<E> {
+  type Output = Result<(), SubscriptionFutureError<E>>;
+
+  fn poll(
+    mut self: std::pin::Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Self::Output> {
+    // The sender is dropped without a message if the subscription is
+    // unsubscribed before the stream terminated.
+    let ret = match ready!(self.receiver.poll_unpin(cx)) {
+      Ok(Ok(())) => Ok(()),
+      Ok(Err(err)) => Err(SubscriptionFutureError::Error(err)),
+      Err(_) => Err(SubscriptionFutureError::Cancelled),
+    };
+    Poll::Ready(ret)
+  }
+}
+
+pub struct SubscribeFutureObserver<N, E> {
+  next: N,
+  sender: oneshot::Sender<Result<(), E>>,
+}
+
+impl<T, E, N> Observer<T, E> for SubscribeFutureObserver<N, E>
+where
+  N: FnMut(T),
+{
+  #[inline]
+  fn next(&mut self, value: T) {
+    (self.next)(value);
+  }
+
+  fn error(self, err: E) {
+    let _ = self.sender.send(Err(err));
+  }
+
+  fn complete(self) {
+    let _ = self.sender.send(Ok(()));
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    // no one awaits the termination anymore.
+    self.sender.is_canceled()
+  }
+}
+
+pub(crate) fn subscribe_future<T, E, S, N>(
+  observable: S,
+  next: N,
+) -> (S::Unsub, SubscriptionFuture<E>)
+where
+  S: Observable<T, E, SubscribeFutureObserver<N, E>>,
+  N: FnMut(T),
+{
+  let (sender, receiver) = oneshot::channel();
+  let unsub =
+    observable.actual_subscribe(SubscribeFutureObserver { next, sender });
+  (unsub, SubscriptionFuture { receiver })
+}
+
 #[cfg(test)]
 mod tests {
   use futures::executor::block_on;
+  use std::convert::Infallible;
 
-  use crate::{observable::ObservableExt, ops::future::ObservableError};
+  use crate::{
+    observable::ObservableExt,
+    ops::future::{ObservableError, SubscriptionFutureError},
+    prelude::*,
+  };
 
   #[tokio::test]
   async fn to_future_observable_resolve_value_test() {
@@ -156,4 +244,68 @@ mod tests {
 
     assert!(matches!(value, Err(ObservableError::MultipleValues)));
   }
+
+  #[test]
+  fn subscribe_future_resolve_on_complete() {
+    let mut values = vec![];
+    let (_, fut) = crate::observable::from_iter([1, 2, 3])
+      .subscribe_future(|v| values.push(v));
+
+    assert_eq!(
+      block_on(fut),
+      Ok::<_, SubscriptionFutureError<Infallible>>(())
+    );
+    assert_eq!(values, vec![1, 2, 3]);
+  }
+
+  #[test]
+  fn subscribe_future_resolve_on_error() {
+    let subject = Subject::default();
+    let (_, fut) = subject.clone().subscribe_future(|_: i32| {});
+    subject.error("oops");
+
+    assert_eq!(block_on(fut), Err(SubscriptionFutureError::Error("oops")));
+  }
+
+  #[test]
+  fn subscribe_future_cancelled_on_unsubscribe() {
+    let subject = Subject::<i32, ()>::default();
+    let (subscription, fut) = subject.clone().subscribe_future(|_| {});
+    subscription.unsubscribe();
+
+    assert_eq!(block_on(fut), Err(SubscriptionFutureError::Cancelled));
+  }
+
+  #[test]
+  fn subscribe_future_stop_source_when_dropped() {
+    use std::{cell::Cell, rc::Rc};
+
+    let emitted = Rc::new(Cell::new(0));
+    let c_emitted = emitted.clone();
+    let mut subject = Subject::<(), Infallible>::default();
+    let (_, fut) = subject
+      .clone()
+      .flat_map(move |_| {
+        let c_emitted = c_emitted.clone();
+        crate::observable::iterate(0, |v| v + 1)
+          .tap(move |_| c_emitted.set(c_emitted.get() + 1))
+      })
+      .subscribe_future(|_| {});
+    drop(fut);
+
+    // an endless source stops once no one awaits the termination.
+    subject.next(());
+    assert_eq!(emitted.get(), 0);
+  }
+
+  #[tokio::test]
+  async fn subscribe_future_await_pipeline() {
+    let pool = FuturesThreadPoolScheduler::new().unwrap();
+    let (_, fut) = crate::observable::from_iter(0..10)
+      .subscribe_on(pool)
+      .map(|v| v * 2)
+      .subscribe_future(|_| {});
+
+    assert_eq!(fut.await, Ok::<_, SubscriptionFutureError<Infallible>>(()));
+  }
 }
@@ -0,0 +1,347 @@
+use crate::{
+  prelude::*,
+  rc::{MutArc, MutRc, RcDeref, RcDerefMut},
+};
+use std::collections::{BTreeMap, VecDeque};
+
+/// Subscribes to at most `concurrent` inner observables at the same time like
+/// `merge_all`, but emits their values in the order the inner observables
+/// were emitted by the source. See [`ObservableExt::merge_all_ordered`].
+pub struct MergeAllOrderedOp<'a, S, ObservableItem> {
+  pub concurrent: usize,
+  pub source: S,
+  _marker: TypeHint<&'a ObservableItem>,
+}
+
+/// The thread safe version of [`MergeAllOrderedOp`].
+pub struct MergeAllOrderedOpThreads<S, ObservableItem> {
+  pub concurrent: usize,
+  pub source: S,
+  _marker: TypeHint<ObservableItem>,
+}
+
+macro_rules! impl_new_method {
+  ($name: ident $(,$lf:lifetime)?) => {
+    impl<$($lf,)? S, ObservableItem> $name<$($lf,)? S, ObservableItem> {
+      #[inline]
+      pub(crate) fn new(source: S, concurrent: usize) -> Self {
+        assert!(concurrent > 0, "`concurrent` must be greater than zero");
+        Self { concurrent, source, _marker: TypeHint::default() }
+      }
+    }
+
+    impl<$($lf,)? S: Clone, ObservableItem> Clone
+      for $name<$($lf,)? S, ObservableItem>
+    {
+      fn clone(&self) -> Self {
+        Self::new(self.source.clone(), self.concurrent)
+      }
+    }
+  };
+}
+
+impl_new_method!(MergeAllOrderedOp, 'a);
+impl_new_method!(MergeAllOrderedOpThreads);
+
+struct OrderedData<O, Item, Lazy> {
+  observer: O,
+  subscribe_tasks: VecDeque<Lazy>,
+  // the index of the inner observable whose values are emitted directly.
+  head: usize,
+  // the buffered values, and if completed, of the inner observables behind
+  // the `head`.
+  buffers: BTreeMap<usize, (Vec<Item>, bool)>,
+  next_index: usize,
+  running: usize,
+  concurrent: usize,
+  outside_completed: bool,
+}
+
+type OrderedDataLocal<'a, O, Item> =
+  OrderedData<O, Item, Box<dyn FnOnce() + 'a>>;
+type OrderedDataThreads<O, Item> =
+  OrderedData<O, Item, Box<dyn FnOnce() + Send>>;
+
+pub struct OutsideObserver<'a, O, Item> {
+  data: MutRc<Option<OrderedDataLocal<'a, O, Item>>>,
+  subscription: MultiSubscription<'a>,
+}
+
+pub struct OutsideObserverThreads<O, Item> {
+  data: MutArc<Option<OrderedDataThreads<O, Item>>>,
+  subscription: MultiSubscriptionThreads,
+}
+
+pub struct InnerObserver<'a, O, Item> {
+  data: MutRc<Option<OrderedDataLocal<'a, O, Item>>>,
+  index: usize,
+}
+
+pub struct InnerObserverThreads<O, Item> {
+  data: MutArc<Option<OrderedDataThreads<O, Item>>>,
+  index: usize,
+}
+
+macro_rules! impl_merge_all_ordered {
+  ($op: ty, $outside: ident, $inner: ident, $rc: ident,
+    $subscription: ty, $box_unsub: ty, $($lf: lifetime)? $(,$send: ident)?) => {
+    impl<$($lf,)? ObservableItem, Item, Err, O, S> Observable<Item, Err, O>
+      for $op
+    where
+      O: Observer<Item, Err> $(+ $lf)? $(+ $send + 'static)?,
+      S: Observable<ObservableItem, Err, $outside<$($lf,)? O, Item>>,
+      ObservableItem: Observable<Item, Err, $inner<$($lf,)? O, Item>>
+        $(+ $lf)? $(+ $send + 'static)?,
+      ObservableItem::Unsub: $($lf)? $($send + 'static)?,
+      S::Unsub: $($lf)? $($send + 'static)?,
+      Item: $($lf)? $($send + 'static)?,
+    {
+      type Unsub = $subscription;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let mut subscription = <$subscription>::default();
+        let data = OrderedData {
+          observer,
+          subscribe_tasks: VecDeque::new(),
+          head: 0,
+          buffers: BTreeMap::new(),
+          next_index: 0,
+          running: 0,
+          concurrent: self.concurrent,
+          outside_completed: false,
+        };
+        let outside = $outside {
+          data: $rc::own(Some(data)),
+          subscription: subscription.clone(),
+        };
+        let unsub = self.source.actual_subscribe(outside);
+        subscription.append(<$box_unsub>::new(unsub));
+        subscription
+      }
+    }
+
+    impl<$($lf,)? ObservableItem, Item, Err, S> ObservableExt<Item, Err>
+      for $op
+    where
+      S: ObservableExt<ObservableItem, Err>,
+      ObservableItem: ObservableExt<Item, Err>,
+    {
+    }
+
+    impl<$($lf,)? ObservableItem, Item, Err, O> Observer<ObservableItem, Err>
+      for $outside<$($lf,)? O, Item>
+    where
+      O: Observer<Item, Err> $(+ $lf)? $(+ $send + 'static)?,
+      ObservableItem: Observable<Item, Err, $inner<$($lf,)? O, Item>>
+        $(+ $lf)? $(+ $send + 'static)?,
+      ObservableItem::Unsub: $($lf)? $($send + 'static)?,
+      Item: $($lf)? $($send + 'static)?,
+    {
+      fn next(&mut self, value: ObservableItem) {
+        let mut guard = self.data.rc_deref_mut();
+        let Some(data) = guard.as_mut() else { return };
+        let index = data.next_index;
+        data.next_index += 1;
+        let inner = $inner { data: self.data.clone(), index };
+        let mut subscription = self.subscription.clone();
+        if data.running < data.concurrent {
+          data.running += 1;
+          drop(guard);
+          let unsub = value.actual_subscribe(inner);
+          subscription.append(<$box_unsub>::new(unsub));
+        } else {
+          data.subscribe_tasks.push_back(Box::new(move || {
+            let unsub = value.actual_subscribe(inner);
+            subscription.append(<$box_unsub>::new(unsub));
+          }));
+        }
+      }
+
+      fn error(self, err: Err) {
+        let data = self.data.rc_deref_mut().take();
+        if let Some(data) = data {
+          data.observer.error(err);
+        }
+      }
+
+      fn complete(self) {
+        let mut guard = self.data.rc_deref_mut();
+        if let Some(data) = guard.as_mut() {
+          data.outside_completed = true;
+          if data.running == 0 && data.subscribe_tasks.is_empty() {
+            guard.take().unwrap().observer.complete();
+          }
+        }
+      }
+
+      fn is_finished(&self) -> bool {
+        self
+          .data
+          .rc_deref()
+          .as_ref()
+          .map_or(true, |data| data.observer.is_finished())
+      }
+    }
+
+    impl<$($lf,)? Item, Err, O> Observer<Item, Err> for $inner<$($lf,)? O, Item>
+    where
+      O: Observer<Item, Err>,
+    {
+      fn next(&mut self, value: Item) {
+        if let Some(data) = self.data.rc_deref_mut().as_mut() {
+          if data.head == self.index {
+            data.observer.next(value);
+          } else {
+            data.buffers.entry(self.index).or_default().0.push(value);
+          }
+        }
+      }
+
+      fn error(self, err: Err) {
+        let data = self.data.rc_deref_mut().take();
+        if let Some(data) = data {
+          data.observer.error(err);
+        }
+      }
+
+      fn complete(self) {
+        let mut guard = self.data.rc_deref_mut();
+        let Some(data) = guard.as_mut() else { return };
+        data.running -= 1;
+        if data.head == self.index {
+          data.head += 1;
+          // flush the inner observables that caught up with the head.
+          while let Some((values, completed)) = data.buffers.remove(&data.head)
+          {
+            values.into_iter().for_each(|v| data.observer.next(v));
+            if !completed {
+              break;
+            }
+            data.head += 1;
+          }
+        } else {
+          data.buffers.entry(self.index).or_default().1 = true;
+        }
+
+        if let Some(task) = data.subscribe_tasks.pop_front() {
+          data.running += 1;
+          drop(guard);
+          task();
+        } else if data.running == 0 && data.outside_completed {
+          guard.take().unwrap().observer.complete();
+        }
+      }
+
+      fn is_finished(&self) -> bool {
+        self
+          .data
+          .rc_deref()
+          .as_ref()
+          .map_or(true, |data| data.observer.is_finished())
+      }
+    }
+  };
+}
+
+impl_merge_all_ordered!(
+  MergeAllOrderedOp<'a, S, ObservableItem>,
+  OutsideObserver,
+  InnerObserver,
+  MutRc,
+  MultiSubscription<'a>,
+  BoxSubscription<'a>,
+  'a
+);
+impl_merge_all_ordered!(
+  MergeAllOrderedOpThreads<S, ObservableItem>,
+  OutsideObserverThreads,
+  InnerObserverThreads,
+  MutArc,
+  MultiSubscriptionThreads,
+  BoxSubscriptionThreads,
+  ,
+  Send
+);
+
+#[cfg(test)]
+mod test {
+  use crate::{observable::fake_timer::FakeClock, prelude::*};
+  use std::{cell::RefCell, convert::Infallible, rc::Rc, time::Duration};
+
+  #[test]
+  fn emit_in_input_order() {
+    let values = Rc::new(RefCell::new(vec![]));
+    let c_values = values.clone();
+    let clock = FakeClock::default();
+    let c_clock = clock.clone();
+
+    // the later requests answer faster.
+    observable::from_iter([30, 20, 10])
+      .map_async_ordered(3, move |ms| {
+        c_clock.delay(Duration::from_millis(ms)).map(move |_| ms)
+      })
+      .subscribe(move |v| c_values.borrow_mut().push(v));
+
+    clock.advance(Duration::from_millis(11));
+    clock.advance(Duration::from_millis(10));
+    assert!(values.borrow().is_empty());
+    clock.advance(Duration::from_millis(10));
+    assert_eq!(&*values.borrow(), &[30, 20, 10]);
+  }
+
+  #[test]
+  fn limit_concurrency() {
+    let mut values = vec![];
+    let mut completed = false;
+    let subscribed = Rc::new(RefCell::new(vec![]));
+    let c_subscribed = subscribed.clone();
+    let mut inners: Vec<Subject<i32, Infallible>> =
+      (0..3).map(|_| Subject::default()).collect();
+    let c_inners = inners.clone();
+
+    observable::from_iter(0..3)
+      .map_async_ordered(2, move |i| {
+        let subscribed = c_subscribed.clone();
+        let inner = c_inners[i].clone();
+        observable::defer(move || {
+          subscribed.borrow_mut().push(i);
+          inner
+        })
+      })
+      .on_complete(|| completed = true)
+      .subscribe(|v| values.push(v));
+
+    assert_eq!(&*subscribed.borrow(), &[0, 1]);
+    inners[1].next(10);
+    inners[1].clone().complete();
+    // the slot of the second inner is released, even if it's buffered.
+    assert_eq!(&*subscribed.borrow(), &[0, 1, 2]);
+    inners[2].next(20);
+    inners[0].next(0);
+    inners[2].clone().complete();
+    inners[0].clone().complete();
+    drop(inners);
+
+    assert_eq!(values, vec![0, 10, 20]);
+    assert!(completed);
+  }
+
+  #[test]
+  fn map_async_ordered_threads() {
+    use crate::ops::complete_status::CompleteStatus;
+    use std::sync::{Arc, Mutex};
+
+    let values = Arc::new(Mutex::new(vec![]));
+    let c_values = values.clone();
+    let pool = FuturesThreadPoolScheduler::new().unwrap();
+    let (o, status) = observable::from_iter(0..5)
+      .map_async_ordered_threads(3, move |i: u64| {
+        observable::timer(i, Duration::from_millis(10 - i * 2), pool.clone())
+      })
+      .complete_status();
+    o.subscribe(move |v| c_values.lock().unwrap().push(v));
+    CompleteStatus::wait_for_end(status);
+
+    assert_eq!(*values.lock().unwrap(), vec![0, 1, 2, 3, 4]);
+  }
+}
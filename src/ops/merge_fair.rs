@@ -0,0 +1,416 @@
+use crate::{
+  ops::combine_latest::ManySubscription,
+  prelude::*,
+  rc::{MutArc, MutRc, RcDeref, RcDerefMut},
+};
+use std::collections::VecDeque;
+
+/// Merges two observables like `merge`, but takes turns between the sources
+/// that have values ready. See [`ObservableExt::merge_fair`].
+#[derive(Clone)]
+pub struct MergeFairOp<S1, S2> {
+  source1: S1,
+  source2: S2,
+}
+
+/// The thread safe version of [`MergeFairOp`].
+#[derive(Clone)]
+pub struct MergeFairOpThreads<S1, S2> {
+  source1: S1,
+  source2: S2,
+}
+
+/// Merges many observables, taking turns between all the sources that have
+/// values ready. See [`merge_fair_all`].
+#[derive(Clone)]
+pub struct MergeFairAllOp<S> {
+  sources: Vec<S>,
+}
+
+/// The thread safe version of [`MergeFairAllOp`].
+#[derive(Clone)]
+pub struct MergeFairAllOpThreads<S> {
+  sources: Vec<S>,
+}
+
+/// Merges all the `sources` like `merge`, but when several of them have
+/// values waiting, they take turns one value each in a round robin, so every
+/// source gets the same share of the downstream whatever its rate. The values
+/// arriving while the downstream is busy are buffered per source until their
+/// turn comes, the others are emitted right away. It completes once all the
+/// sources completed, and errors with the first error.
+///
+/// # Example
+///
+/// ```
+/// # use rxrust::prelude::*;
+/// # use std::{cell::RefCell, convert::Infallible, rc::Rc};
+/// let values = Rc::new(RefCell::new(vec![]));
+/// let mut requests = Subject::<_, Infallible>::default();
+/// let (logs, metrics) = (Subject::default(), Subject::default());
+/// let (mut c_logs, mut c_metrics) = (logs.clone(), metrics.clone());
+/// let c_values = values.clone();
+/// observable::merge_fair_all([requests.clone(), logs, metrics]).subscribe(
+///   move |v| {
+///     c_values.borrow_mut().push(v);
+///     // handling a request produces logs and metrics meanwhile.
+///     if v == "request" {
+///       c_logs.next("log");
+///       c_logs.next("log");
+///       c_logs.next("log");
+///       c_metrics.next("metric");
+///     }
+///   },
+/// );
+///
+/// requests.next("request");
+/// assert_eq!(*values.borrow(), ["request", "log", "metric", "log", "log"]);
+/// ```
+pub fn merge_fair_all<S>(
+  sources: impl IntoIterator<Item = S>,
+) -> MergeFairAllOp<S> {
+  MergeFairAllOp { sources: sources.into_iter().collect() }
+}
+
+/// The thread safe version of [`merge_fair_all`].
+pub fn merge_fair_all_threads<S>(
+  sources: impl IntoIterator<Item = S>,
+) -> MergeFairAllOpThreads<S> {
+  MergeFairAllOpThreads { sources: sources.into_iter().collect() }
+}
+
+pub struct FairData<O, Item, Err> {
+  observer: Option<O>,
+  // the values of every source waiting for their turn.
+  queues: Vec<VecDeque<Item>>,
+  turn: usize,
+  completed: Vec<bool>,
+  error: Option<Err>,
+  // someone is emitting to the downstream, the observer is taken out.
+  dispatching: bool,
+}
+
+impl<O, Item, Err> FairData<O, Item, Err> {
+  fn new(observer: O, sources: usize) -> Self {
+    FairData {
+      observer: Some(observer),
+      queues: (0..sources).map(|_| VecDeque::new()).collect(),
+      turn: 0,
+      completed: vec![false; sources],
+      error: None,
+      dispatching: false,
+    }
+  }
+}
+
+pub struct MergeFairObserver<Data> {
+  data: Data,
+  index: usize,
+}
+
+macro_rules! impl_merge_fair_op {
+  ($name: ident, $all_name: ident, $rc: ident) => {
+    impl<S1, S2> $name<S1, S2> {
+      #[inline]
+      pub fn new(source1: S1, source2: S2) -> Self {
+        $name { source1, source2 }
+      }
+    }
+
+    impl<S1, S2, Item, Err, O> Observable<Item, Err, O> for $name<S1, S2>
+    where
+      O: Observer<Item, Err>,
+      S1: Observable<Item, Err, MergeFairObserver<$rc<FairData<O, Item, Err>>>>,
+      S2: Observable<Item, Err, MergeFairObserver<$rc<FairData<O, Item, Err>>>>,
+    {
+      type Unsub = ZipSubscription<S1::Unsub, S2::Unsub>;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let data = $rc::own(FairData::new(observer, 2));
+        let a = self
+          .source1
+          .actual_subscribe(MergeFairObserver { data: data.clone(), index: 0 });
+        let b = self
+          .source2
+          .actual_subscribe(MergeFairObserver { data, index: 1 });
+        ZipSubscription::new(a, b)
+      }
+    }
+
+    impl<S1, S2, Item, Err> ObservableExt<Item, Err> for $name<S1, S2>
+    where
+      S1: ObservableExt<Item, Err>,
+      S2: ObservableExt<Item, Err>,
+    {
+    }
+
+    impl<S, Item, Err, O> Observable<Item, Err, O> for $all_name<S>
+    where
+      O: Observer<Item, Err>,
+      S: Observable<Item, Err, MergeFairObserver<$rc<FairData<O, Item, Err>>>>,
+    {
+      type Unsub = ManySubscription<S::Unsub>;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let data = $rc::own(FairData::new(observer, self.sources.len()));
+        let unsubs = self
+          .sources
+          .into_iter()
+          .enumerate()
+          .map(|(index, s)| {
+            s.actual_subscribe(MergeFairObserver { data: data.clone(), index })
+          })
+          .collect();
+        // complete right away without any source.
+        MergeFairObserver { data, index: 0 }.notify(|_| {});
+        ManySubscription(unsubs)
+      }
+    }
+
+    impl<S, Item, Err> ObservableExt<Item, Err> for $all_name<S> where
+      S: ObservableExt<Item, Err>
+    {
+    }
+
+    impl<O, Item, Err> MergeFairObserver<$rc<FairData<O, Item, Err>>>
+    where
+      O: Observer<Item, Err>,
+    {
+      // Emits the waiting values turn by turn, until no value is ready.
+      fn drain(&self) {
+        loop {
+          let mut guard = self.data.rc_deref_mut();
+          let data = &mut *guard;
+          if let Some(err) = data.error.take() {
+            data.dispatching = false;
+            data.queues.iter_mut().for_each(|q| q.clear());
+            if let Some(observer) = data.observer.take() {
+              observer.error(err);
+            }
+            return;
+          }
+
+          let len = data.queues.len();
+          let turn = data.turn;
+          let side = (0..len)
+            .map(|i| (turn + i) % len)
+            .find(|i| !data.queues[*i].is_empty());
+          if let Some(side) = side {
+            let value = data.queues[side].pop_front().unwrap();
+            data.turn = (side + 1) % len;
+            let mut observer = data.observer.take();
+            drop(guard);
+            if let Some(observer) = observer.as_mut() {
+              observer.next(value);
+            }
+            self.data.rc_deref_mut().observer = observer;
+          } else {
+            data.dispatching = false;
+            if data.completed.iter().all(|c| *c) {
+              if let Some(observer) = data.observer.take() {
+                observer.complete();
+              }
+            }
+            return;
+          }
+        }
+      }
+
+      fn notify(&self, f: impl FnOnce(&mut FairData<O, Item, Err>)) {
+        let mut data = self.data.rc_deref_mut();
+        if data.observer.is_none() && !data.dispatching {
+          return;
+        }
+        f(&mut data);
+        if !data.dispatching {
+          data.dispatching = true;
+          drop(data);
+          self.drain();
+        }
+      }
+    }
+
+    impl<O, Item, Err> Observer<Item, Err>
+      for MergeFairObserver<$rc<FairData<O, Item, Err>>>
+    where
+      O: Observer<Item, Err>,
+    {
+      fn next(&mut self, value: Item) {
+        let index = self.index;
+        self.notify(|data| data.queues[index].push_back(value));
+      }
+
+      fn error(self, err: Err) {
+        self.notify(|data| {
+          if data.error.is_none() {
+            data.error = Some(err);
+          }
+        });
+      }
+
+      fn complete(self) {
+        let index = self.index;
+        self.notify(|data| data.completed[index] = true);
+      }
+
+      fn is_finished(&self) -> bool {
+        let data = self.data.rc_deref();
+        !data.dispatching
+          && data.observer.as_ref().map_or(true, |o| o.is_finished())
+      }
+    }
+  };
+}
+
+impl_merge_fair_op!(MergeFairOp, MergeFairAllOp, MutRc);
+impl_merge_fair_op!(MergeFairOpThreads, MergeFairAllOpThreads, MutArc);
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::{cell::RefCell, convert::Infallible, rc::Rc};
+
+  #[test]
+  fn emit_synchronous_sources_in_order() {
+    let mut values = vec![];
+    let mut completed = false;
+    observable::from_iter([1, 2, 3, 4])
+      .merge_fair(observable::from_iter([10, 20]))
+      .on_complete(|| completed = true)
+      .subscribe(|v| values.push(v));
+
+    assert_eq!(values, vec![1, 2, 3, 4, 10, 20]);
+    assert!(completed);
+  }
+
+  #[test]
+  fn take_from_endless_source() {
+    let mut values = vec![];
+    observable::iterate(0, |v| v + 1)
+      .merge_fair(observable::of(100))
+      .take(3)
+      .subscribe(|v| values.push(v));
+
+    assert_eq!(values, vec![0, 1, 2]);
+  }
+
+  #[test]
+  fn fast_source_not_starve_slow_one() {
+    let values = Rc::new(RefCell::new(vec![]));
+    let c_values = values.clone();
+    let mut first = Subject::<_, Infallible>::default();
+    let burst = Subject::default();
+    let mut slow = Subject::default();
+    let mut c_burst = burst.clone();
+    let mut c_slow = slow.clone();
+
+    observable::merge_fair_all([first.clone(), burst, slow.clone()]).subscribe(
+      move |v: &str| {
+        c_values.borrow_mut().push(v);
+        // the first log produces more logs, a metric arrives meanwhile.
+        if c_values.borrow().len() == 1 {
+          c_burst.next("log");
+          c_burst.next("log");
+          c_burst.next("log");
+          c_slow.next("metric");
+        }
+      },
+    );
+
+    first.next("log");
+    assert_eq!(&*values.borrow(), &["log", "log", "metric", "log", "log"]);
+
+    // values are emitted directly when no one waits.
+    slow.next("metric");
+    assert_eq!(values.borrow().last(), Some(&"metric"));
+  }
+
+  #[test]
+  fn merge_fair_all_equal_turns() {
+    let values = Rc::new(RefCell::new(vec![]));
+    let c_values = values.clone();
+    let mut start = Subject::<_, Infallible>::default();
+    let (a, b, c) =
+      (Subject::default(), Subject::default(), Subject::default());
+    let (mut c_a, mut c_b, mut c_c) = (a.clone(), b.clone(), c.clone());
+
+    observable::merge_fair_all([start.clone(), a, b, c]).subscribe(move |v| {
+      c_values.borrow_mut().push(v);
+      if v == 0 {
+        [1, 2, 3, 4].into_iter().for_each(|v| c_a.next(v));
+        [10, 20].into_iter().for_each(|v| c_b.next(v));
+        [100, 200, 300].into_iter().for_each(|v| c_c.next(v));
+      }
+    });
+
+    start.next(0);
+    assert_eq!(&*values.borrow(), &[0, 1, 10, 100, 2, 20, 200, 3, 300, 4]);
+  }
+
+  #[test]
+  fn merge_fair_all_empty() {
+    let mut completed = false;
+    observable::merge_fair_all(Vec::<Subject<i32, Infallible>>::new())
+      .on_complete(|| completed = true)
+      .subscribe(|_| {});
+
+    assert!(completed);
+  }
+
+  #[test]
+  fn complete_when_both_completed() {
+    let mut completed = false;
+    let mut a = Subject::<i32, Infallible>::default();
+    let b = Subject::default();
+
+    a.clone()
+      .merge_fair(b.clone())
+      .on_complete(|| completed = true)
+      .subscribe(|_| {});
+
+    a.next(1);
+    a.complete();
+    b.complete();
+    assert!(completed);
+  }
+
+  #[test]
+  fn error_once() {
+    let mut errors = 0;
+    let a = Subject::<i32, &str>::default();
+    let b = Subject::default();
+
+    a.clone()
+      .merge_fair(b.clone())
+      .on_error(|_| errors += 1)
+      .subscribe(|_| {});
+
+    a.error("oops");
+    b.error("oops");
+    assert_eq!(errors, 1);
+  }
+
+  #[test]
+  fn merge_fair_threads() {
+    use crate::rc::{MutArc, RcDeref, RcDerefMut};
+
+    let values = MutArc::own(vec![]);
+    let c_values = values.clone();
+    observable::from_iter([1, 2])
+      .merge_fair_threads(observable::from_iter([10, 20]))
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    assert_eq!(*values.rc_deref(), vec![1, 2, 10, 20]);
+
+    let values = MutArc::own(vec![]);
+    let c_values = values.clone();
+    observable::merge_fair_all_threads([
+      observable::from_iter(vec![1, 2]),
+      observable::from_iter(vec![10]),
+      observable::from_iter(vec![100, 200]),
+    ])
+    .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    assert_eq!(*values.rc_deref(), vec![1, 2, 10, 100, 200]);
+  }
+}
@@ -0,0 +1,165 @@
+use crate::{
+  prelude::*,
+  rc::{MutArc, RcDerefMut},
+};
+use std::time::Duration;
+
+/// Mirrors the source, and reports the number of items emitted in every
+/// `interval`. See [`ObservableExt::metered`].
+#[derive(Clone)]
+pub struct MeteredOp<S, F, SD> {
+  pub(crate) source: S,
+  pub(crate) interval: Duration,
+  pub(crate) callback: F,
+  pub(crate) scheduler: SD,
+}
+
+pub struct Meter<F> {
+  count: usize,
+  callback: Option<F>,
+}
+
+type RcMeter<F> = MutArc<Meter<F>>;
+type RcHandler = MutArc<Option<TaskHandle<NormalReturn<()>>>>;
+
+impl<Item, Err, O, S, F, SD> Observable<Item, Err, O> for MeteredOp<S, F, SD>
+where
+  O: Observer<Item, Err>,
+  S: Observable<Item, Err, MeteredObserver<O, F>>,
+  F: FnMut(usize),
+  SD: Scheduler<RepeatTask<RcMeter<F>>>,
+{
+  type Unsub = ZipSubscription<S::Unsub, RcHandler>;
+
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    let Self { source, interval, callback, scheduler } = self;
+    let meter = MutArc::own(Meter { count: 0, callback: Some(callback) });
+    let task = RepeatTask::new(interval, report_task, meter.clone());
+    let handler = scheduler.schedule(task, Some(interval));
+    let task_handler = MutArc::own(Some(handler));
+
+    let unsub = source.actual_subscribe(MeteredObserver {
+      observer,
+      meter,
+      task_handler: task_handler.clone(),
+    });
+    ZipSubscription::new(unsub, task_handler)
+  }
+}
+
+impl<Item, Err, S, F, SD> ObservableExt<Item, Err> for MeteredOp<S, F, SD> where
+  S: ObservableExt<Item, Err>
+{
+}
+
+fn report_task<F: FnMut(usize)>(meter: &mut RcMeter<F>, _: usize) -> bool {
+  let mut meter = meter.rc_deref_mut();
+  let count = std::mem::take(&mut meter.count);
+  if let Some(callback) = meter.callback.as_mut() {
+    callback(count);
+    true
+  } else {
+    false
+  }
+}
+
+pub struct MeteredObserver<O, F> {
+  observer: O,
+  meter: RcMeter<F>,
+  task_handler: RcHandler,
+}
+
+impl<O, F: FnMut(usize)> MeteredObserver<O, F> {
+  fn report_rest(&self) {
+    self.task_handler.clone().unsubscribe();
+    let mut meter = self.meter.rc_deref_mut();
+    let count = std::mem::take(&mut meter.count);
+    if let Some(mut callback) = meter.callback.take() {
+      callback(count);
+    }
+  }
+}
+
+impl<Item, Err, O, F> Observer<Item, Err> for MeteredObserver<O, F>
+where
+  O: Observer<Item, Err>,
+  F: FnMut(usize),
+{
+  fn next(&mut self, value: Item) {
+    self.meter.rc_deref_mut().count += 1;
+    self.observer.next(value)
+  }
+
+  fn error(self, err: Err) {
+    self.report_rest();
+    self.observer.error(err)
+  }
+
+  fn complete(self) {
+    self.report_rest();
+    self.observer.complete()
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.observer.is_finished()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{
+    prelude::*,
+    rc::{MutRc, RcDeref, RcDerefMut},
+  };
+  use futures::executor::LocalPool;
+
+  #[test]
+  fn report_rest_count_on_complete() {
+    let mut pool = LocalPool::new();
+    let reports = MutRc::own(vec![]);
+    let c_reports = reports.clone();
+    let values = MutRc::own(vec![]);
+    let c_values = values.clone();
+    let mut subject = Subject::default();
+
+    subject
+      .clone()
+      .metered(
+        Duration::from_secs(3600),
+        move |count| c_reports.rc_deref_mut().push(count),
+        pool.spawner(),
+      )
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    subject.next(1);
+    subject.next(2);
+    subject.next(3);
+    subject.complete();
+    pool.run_until_stalled();
+
+    assert_eq!(*values.rc_deref(), vec![1, 2, 3]);
+    assert_eq!(*reports.rc_deref(), vec![3]);
+  }
+
+  #[test]
+  fn report_periodically() {
+    let mut pool = LocalPool::new();
+    let reports = MutRc::own(vec![]);
+    let c_reports = reports.clone();
+
+    observable::interval(Duration::from_millis(1), pool.spawner())
+      .take(20)
+      .metered(
+        Duration::from_millis(5),
+        move |count| c_reports.rc_deref_mut().push(count),
+        pool.spawner(),
+      )
+      .subscribe(|_| {});
+    pool.run();
+
+    let reports = reports.rc_deref();
+    assert!(reports.len() > 1);
+    assert_eq!(reports.iter().sum::<usize>(), 20);
+  }
+}
@@ -0,0 +1,154 @@
+use crate::prelude::*;
+
+/// Folds the source values into an accumulator and emits it once the source
+/// completes. See [`ObservableExt::reduce_initial`].
+///
+/// The accumulator is kept in the observer, nothing is cloned or emitted to
+/// the downstream for the intermediate steps.
+#[derive(Clone)]
+pub struct ReduceOp<Source, BinaryOp, OutputItem, InputItem> {
+  source: Source,
+  binary_op: BinaryOp,
+  initial: OutputItem,
+  _hint: TypeHint<InputItem>,
+}
+
+impl<Source, BinaryOp, OutputItem, InputItem>
+  ReduceOp<Source, BinaryOp, OutputItem, InputItem>
+{
+  #[inline]
+  pub(crate) fn new(
+    source: Source,
+    binary_op: BinaryOp,
+    initial: OutputItem,
+  ) -> Self {
+    Self {
+      source,
+      binary_op,
+      initial,
+      _hint: TypeHint::default(),
+    }
+  }
+}
+
+impl<Source, BinaryOp, OutputItem, InputItem, Err, O>
+  Observable<OutputItem, Err, O>
+  for ReduceOp<Source, BinaryOp, OutputItem, InputItem>
+where
+  O: Observer<OutputItem, Err>,
+  Source: Observable<InputItem, Err, ReduceObserver<O, BinaryOp, OutputItem>>,
+  BinaryOp: FnMut(OutputItem, InputItem) -> OutputItem,
+{
+  type Unsub = Source::Unsub;
+
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    self.source.actual_subscribe(ReduceObserver {
+      observer,
+      binary_op: self.binary_op,
+      acc: Some(self.initial),
+    })
+  }
+}
+
+impl<Source, BinaryOp, OutputItem, InputItem, Err>
+  ObservableExt<OutputItem, Err>
+  for ReduceOp<Source, BinaryOp, OutputItem, InputItem>
+where
+  Source: ObservableExt<InputItem, Err>,
+  BinaryOp: FnMut(OutputItem, InputItem) -> OutputItem,
+{
+}
+
+pub struct ReduceObserver<O, BinaryOp, OutputItem> {
+  observer: O,
+  binary_op: BinaryOp,
+  // taken out while the binary operator runs.
+  acc: Option<OutputItem>,
+}
+
+impl<O, BinaryOp, InputItem, OutputItem, Err> Observer<InputItem, Err>
+  for ReduceObserver<O, BinaryOp, OutputItem>
+where
+  O: Observer<OutputItem, Err>,
+  BinaryOp: FnMut(OutputItem, InputItem) -> OutputItem,
+{
+  fn next(&mut self, value: InputItem) {
+    if let Some(acc) = self.acc.take() {
+      self.acc = Some((self.binary_op)(acc, value));
+    }
+  }
+
+  #[inline]
+  fn error(self, err: Err) {
+    self.observer.error(err)
+  }
+
+  fn complete(mut self) {
+    if let Some(acc) = self.acc.take() {
+      self.observer.next(acc);
+    }
+    self.observer.complete()
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.observer.is_finished()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::{cell::Cell, rc::Rc};
+
+  // counts the clones, to check no intermediate accumulator is copied.
+  struct Summary {
+    total: u64,
+    clones: Rc<Cell<usize>>,
+  }
+
+  impl Clone for Summary {
+    fn clone(&self) -> Self {
+      self.clones.set(self.clones.get() + 1);
+      Summary {
+        total: self.total,
+        clones: self.clones.clone(),
+      }
+    }
+  }
+
+  #[test]
+  fn emit_only_the_final_accumulator() {
+    let clones = Rc::new(Cell::new(0));
+    let mut emitted = vec![];
+    observable::from_iter(1..=10_000u64)
+      .reduce_initial(
+        Summary { total: 0, clones: clones.clone() },
+        |mut acc, v| {
+          acc.total += v;
+          acc
+        },
+      )
+      .subscribe(|s| emitted.push(s.total));
+
+    assert_eq!(emitted, vec![50_005_000]);
+    assert_eq!(clones.get(), 0);
+  }
+
+  #[test]
+  fn drop_accumulator_on_error() {
+    let mut emitted = vec![];
+    let mut error = None;
+    let mut source = Subject::default();
+    source
+      .clone()
+      .reduce_initial(0, |acc, v: i32| acc + v)
+      .on_error(|e| error = Some(e))
+      .subscribe(|v| emitted.push(v));
+
+    source.next(1);
+    source.error("oops");
+    assert!(emitted.is_empty());
+    assert_eq!(error, Some("oops"));
+  }
+}
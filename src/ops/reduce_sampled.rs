@@ -0,0 +1,248 @@
+use crate::{
+  prelude::*,
+  rc::{MutArc, MutRc, RcDeref, RcDerefMut},
+};
+
+/// Accumulates the source values like `reduce`, but emits the current
+/// accumulator every time the sampler emits. See
+/// [`ObservableExt::reduce_sampled`].
+#[derive(Clone)]
+pub struct ReduceSampledOp<Source, Sample, BinaryOp, OutputItem, Hint> {
+  source: Source,
+  sample: Sample,
+  binary_op: BinaryOp,
+  initial_value: OutputItem,
+  _hint: TypeHint<Hint>,
+}
+
+/// The thread safe version of [`ReduceSampledOp`].
+#[derive(Clone)]
+pub struct ReduceSampledOpThreads<Source, Sample, BinaryOp, OutputItem, Hint> {
+  source: Source,
+  sample: Sample,
+  binary_op: BinaryOp,
+  initial_value: OutputItem,
+  _hint: TypeHint<Hint>,
+}
+
+macro_rules! impl_reduce_sampled_op {
+  ($name: ident, $rc: ident) => {
+    impl<Source, Sample, BinaryOp, OutputItem, Hint>
+      $name<Source, Sample, BinaryOp, OutputItem, Hint>
+    {
+      #[inline]
+      pub(crate) fn new(
+        source: Source,
+        sample: Sample,
+        binary_op: BinaryOp,
+        initial_value: OutputItem,
+      ) -> Self {
+        Self {
+          source,
+          sample,
+          binary_op,
+          initial_value,
+          _hint: TypeHint::default(),
+        }
+      }
+    }
+
+    impl<InputItem, SampleItem, OutputItem, Err, Source, Sample, BinaryOp, O>
+      Observable<OutputItem, Err, O>
+      for $name<Source, Sample, BinaryOp, OutputItem, (InputItem, SampleItem)>
+    where
+      O: Observer<OutputItem, Err>,
+      Source: Observable<
+        InputItem,
+        Err,
+        ReduceSourceObserver<$rc<Option<O>>, $rc<Option<OutputItem>>, BinaryOp>,
+      >,
+      Sample: Observable<
+        SampleItem,
+        Err,
+        ReduceSampleObserver<$rc<Option<O>>, $rc<Option<OutputItem>>>,
+      >,
+      BinaryOp: FnMut(OutputItem, InputItem) -> OutputItem,
+      OutputItem: Clone,
+    {
+      type Unsub = ZipSubscription<Source::Unsub, Sample::Unsub>;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let acc = $rc::own(Some(self.initial_value));
+        let observer = $rc::own(Some(observer));
+        let source_observer = ReduceSourceObserver {
+          observer: observer.clone(),
+          acc: acc.clone(),
+          binary_op: self.binary_op,
+        };
+        let sample_observer = ReduceSampleObserver { observer, acc };
+
+        let source_unsub = self.source.actual_subscribe(source_observer);
+        let sample_unsub = self.sample.actual_subscribe(sample_observer);
+        ZipSubscription::new(source_unsub, sample_unsub)
+      }
+    }
+
+    impl<InputItem, SampleItem, OutputItem, Err, Source, Sample, BinaryOp>
+      ObservableExt<OutputItem, Err>
+      for $name<Source, Sample, BinaryOp, OutputItem, (InputItem, SampleItem)>
+    where
+      Source: ObservableExt<InputItem, Err>,
+      Sample: ObservableExt<SampleItem, Err>,
+    {
+    }
+  };
+}
+
+impl_reduce_sampled_op!(ReduceSampledOp, MutRc);
+impl_reduce_sampled_op!(ReduceSampledOpThreads, MutArc);
+
+pub struct ReduceSourceObserver<O, Acc, BinaryOp> {
+  observer: O,
+  acc: Acc,
+  binary_op: BinaryOp,
+}
+
+impl<InputItem, OutputItem, Err, O, Acc, BinaryOp> Observer<InputItem, Err>
+  for ReduceSourceObserver<O, Acc, BinaryOp>
+where
+  O: Observer<OutputItem, Err>,
+  Acc: RcDerefMut<Target = Option<OutputItem>>,
+  BinaryOp: FnMut(OutputItem, InputItem) -> OutputItem,
+{
+  fn next(&mut self, value: InputItem) {
+    let mut acc = self.acc.rc_deref_mut();
+    if let Some(v) = acc.take() {
+      *acc = Some((self.binary_op)(v, value));
+    }
+  }
+
+  #[inline]
+  fn error(self, err: Err) {
+    self.observer.error(err)
+  }
+
+  fn complete(mut self) {
+    let acc = self.acc.rc_deref_mut().take();
+    if let Some(acc) = acc {
+      self.observer.next(acc);
+    }
+    self.observer.complete()
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.observer.is_finished()
+  }
+}
+
+pub struct ReduceSampleObserver<O, Acc> {
+  observer: O,
+  acc: Acc,
+}
+
+impl<SampleItem, OutputItem, Err, O, Acc> Observer<SampleItem, Err>
+  for ReduceSampleObserver<O, Acc>
+where
+  O: Observer<OutputItem, Err>,
+  Acc: RcDeref<Target = Option<OutputItem>>,
+  OutputItem: Clone,
+{
+  fn next(&mut self, _: SampleItem) {
+    let acc = self.acc.rc_deref().clone();
+    if let Some(acc) = acc {
+      self.observer.next(acc)
+    }
+  }
+
+  #[inline]
+  fn error(self, err: Err) {
+    self.observer.error(err)
+  }
+
+  #[inline]
+  fn complete(self) {}
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.observer.is_finished()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{observable::fake_timer::FakeClock, prelude::*};
+  use std::{cell::RefCell, rc::Rc, time::Duration};
+
+  #[test]
+  fn running_total_on_tick() {
+    let clock = FakeClock::default();
+    let totals = Rc::new(RefCell::new(vec![]));
+    let c_totals = totals.clone();
+
+    clock
+      .interval(Duration::from_millis(1))
+      .take(25)
+      .reduce_sampled(
+        0,
+        |acc, v| acc + v,
+        clock.interval(Duration::from_millis(10)),
+      )
+      .subscribe(move |v| c_totals.borrow_mut().push(v));
+
+    clock.advance(Duration::from_millis(11));
+    assert_eq!(&*totals.borrow(), &[45]);
+    clock.advance(Duration::from_millis(10));
+    assert_eq!(&*totals.borrow(), &[45, 190]);
+    clock.advance(Duration::from_millis(10));
+    // the final accumulator is emitted when the source completes.
+    assert_eq!(&*totals.borrow(), &[45, 190, 300]);
+  }
+
+  #[test]
+  fn emit_unchanged_accumulator() {
+    let mut totals = vec![];
+    let mut completed = false;
+    let mut source = Subject::default();
+    let mut sampler = Subject::default();
+
+    source
+      .clone()
+      .reduce_sampled(0, |acc, v| acc + v, sampler.clone())
+      .on_complete(|| completed = true)
+      .subscribe(|v| totals.push(v));
+
+    sampler.next(());
+    source.next(1);
+    source.next(2);
+    sampler.next(());
+    sampler.next(());
+    source.complete();
+    drop(sampler);
+
+    assert_eq!(totals, vec![0, 3, 3, 3]);
+    assert!(completed);
+  }
+
+  #[cfg(not(target_arch = "wasm32"))]
+  #[test]
+  fn reduce_sampled_threads() {
+    use crate::rc::{MutArc, RcDeref, RcDerefMut};
+
+    let mut source = SubjectThreads::default();
+    let mut sampler = SubjectThreads::default();
+    let totals = MutArc::own(vec![]);
+    let c_totals = totals.clone();
+    source
+      .clone()
+      .reduce_sampled_threads(0, |acc, v| acc + v, sampler.clone())
+      .subscribe(move |v: i32| c_totals.rc_deref_mut().push(v));
+
+    source.next(1);
+    sampler.next(());
+    source.next(2);
+    sampler.next(());
+
+    assert_eq!(*totals.rc_deref(), vec![1, 3]);
+  }
+}
@@ -172,6 +172,37 @@ mod test {
     assert_eq!(accept2, 1);
   }
 
+  #[test]
+  fn share_local_interval() {
+    use futures::executor::LocalPool;
+    use std::{cell::RefCell, rc::Rc};
+
+    let mut local = LocalPool::new();
+    let spawner = local.spawner();
+    let subscribed = Rc::new(RefCell::new(0));
+    let c_subscribed = subscribed.clone();
+    // nothing here is `Send`, the subscribers capture `Rc`s.
+    let first = Rc::new(RefCell::new(vec![]));
+    let second = Rc::new(RefCell::new(vec![]));
+    let (c_first, c_second) = (first.clone(), second.clone());
+
+    let shared = observable::defer(move || {
+      *c_subscribed.borrow_mut() += 1;
+      observable::interval(Duration::from_millis(1), spawner.clone())
+    })
+    .take(3)
+    .share();
+    shared
+      .clone()
+      .subscribe(move |v| c_first.borrow_mut().push(v));
+    shared.subscribe(move |v| c_second.borrow_mut().push(v));
+    local.run();
+
+    assert_eq!(*subscribed.borrow(), 1);
+    assert_eq!(*first.borrow(), vec![0, 1, 2]);
+    assert_eq!(*second.borrow(), vec![0, 1, 2]);
+  }
+
   #[test]
   fn bench() {
     do_bench();
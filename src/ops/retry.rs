@@ -0,0 +1,568 @@
+use crate::{
+  prelude::*,
+  rc::{MutArc, MutRc},
+};
+use std::{collections::VecDeque, fmt, rc::Rc, sync::Arc};
+
+/// Decides if a failed subscription may be retried, see [`RetryOp`].
+pub trait RetryBudget {
+  /// Consumes a retry, returns `false` if no retry is left.
+  fn take_retry(&mut self) -> bool;
+}
+
+/// A fixed number of retries.
+impl RetryBudget for usize {
+  fn take_retry(&mut self) -> bool {
+    if *self == 0 {
+      false
+    } else {
+      *self -= 1;
+      true
+    }
+  }
+}
+
+/// At most `max` retries within any time window of `window`, measured on the
+/// clock of the scheduler. See [`ObservableExt::retry_windowed`].
+#[derive(Clone)]
+pub struct WindowedRetries<SD> {
+  max: usize,
+  window: Duration,
+  scheduler: SD,
+  // the instants of the retries still in the window.
+  retries: VecDeque<Instant>,
+}
+
+impl<SD> WindowedRetries<SD> {
+  pub fn new(max: usize, window: Duration, scheduler: SD) -> Self {
+    Self {
+      max,
+      window,
+      scheduler,
+      retries: VecDeque::new(),
+    }
+  }
+}
+
+impl<SD> RetryBudget for WindowedRetries<SD>
+where
+  SD: Scheduler<OnceTask<(), NormalReturn<()>>>,
+{
+  fn take_retry(&mut self) -> bool {
+    let now = self.scheduler.now();
+    while self
+      .retries
+      .front()
+      .is_some_and(|at| now.duration_since(*at) >= self.window)
+    {
+      self.retries.pop_front();
+    }
+    if self.retries.len() < self.max {
+      self.retries.push_back(now);
+      true
+    } else {
+      false
+    }
+  }
+}
+
+/// Resubscribes to the source observable when it errors, as long as the
+/// `budget` allows, by default at most a number of times. See
+/// [`ObservableExt::retry`].
+#[derive(Clone)]
+pub struct RetryOp<'a, S, B = usize> {
+  pub(crate) source: S,
+  pub(crate) budget: B,
+  _hint: TypeHint<&'a ()>,
+}
+
+/// The thread safe version of [`RetryOp`].
+#[derive(Clone)]
+pub struct RetryOpThreads<S, B = usize> {
+  pub(crate) source: S,
+  pub(crate) budget: B,
+}
+
+impl<'a, S, B> RetryOp<'a, S, B> {
+  #[inline]
+  pub(crate) fn new(source: S, budget: B) -> Self {
+    Self { source, budget, _hint: TypeHint::new() }
+  }
+}
+
+impl<S, B> RetryOpThreads<S, B> {
+  #[inline]
+  pub(crate) fn new(source: S, budget: B) -> Self {
+    Self { source, budget }
+  }
+}
+
+pub struct RetryObserver<'a, O, B> {
+  observer: MutRc<Option<O>>,
+  remaining: B,
+  resubscribe: Rc<dyn Fn(RetryObserver<'a, O, B>) + 'a>,
+}
+
+pub struct RetryObserverThreads<O, B> {
+  observer: MutArc<Option<O>>,
+  remaining: B,
+  resubscribe: Arc<dyn Fn(RetryObserverThreads<O, B>) + Send + Sync>,
+}
+
+macro_rules! impl_retry {
+  ($op: ty, $observer: ty, $rc: ident, $ptr: ident, $subscription: ty,
+    $box_unsub: ident, $($lf: lifetime)? $(,$send: ident)?) => {
+    impl<$($lf,)? Item, Err, O, S, B> Observable<Item, Err, O> for $op
+    where
+      O: Observer<Item, Err> $(+ $lf)? $(+ $send + 'static)?,
+      S: Observable<Item, Err, $observer>
+        + Clone $(+ $lf)? $(+ $send + Sync + 'static)?,
+      S::Unsub: $($lf)? $($send + 'static)?,
+      B: RetryBudget $(+ $lf)? $(+ $send + 'static)?,
+    {
+      type Unsub = $subscription;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let subscription = <$subscription>::default();
+        let c_subscription = subscription.clone();
+        let source = self.source;
+        let resubscribe = $ptr::new(move |observer: $observer| {
+          let unsub = source.clone().actual_subscribe(observer);
+          let mut subscription = c_subscription.clone();
+          // the failed subscriptions are already closed, drop them so
+          // retrying doesn't accumulate teardowns.
+          subscription.retain();
+          subscription.append($box_unsub::new(unsub));
+        });
+        let observer = <$observer>::new($rc::own(Some(observer)), self.budget, resubscribe.clone());
+        resubscribe(observer);
+        subscription
+      }
+    }
+
+    impl<$($lf,)? Item, Err, S, B> ObservableExt<Item, Err> for $op
+    where
+      S: ObservableExt<Item, Err>
+    {
+    }
+
+    impl<$($lf,)? Item, Err, O, B> Observer<Item, Err> for $observer
+    where
+      O: Observer<Item, Err>,
+      B: RetryBudget,
+    {
+      #[inline]
+      fn next(&mut self, value: Item) {
+        self.observer.next(value)
+      }
+
+      fn error(self, err: Err) {
+        let Self { observer, mut remaining, resubscribe } = self;
+        if observer.is_finished() || !remaining.take_retry() {
+          observer.error(err);
+        } else {
+          let retry = <$observer>::new(observer, remaining, resubscribe.clone());
+          resubscribe(retry);
+        }
+      }
+
+      #[inline]
+      fn complete(self) {
+        self.observer.complete()
+      }
+
+      #[inline]
+      fn is_finished(&self) -> bool {
+        self.observer.is_finished()
+      }
+    }
+  };
+}
+
+impl<'a, O, B> RetryObserver<'a, O, B> {
+  fn new(
+    observer: MutRc<Option<O>>,
+    remaining: B,
+    resubscribe: Rc<dyn Fn(RetryObserver<'a, O, B>) + 'a>,
+  ) -> Self {
+    Self { observer, remaining, resubscribe }
+  }
+}
+
+impl<O, B> RetryObserverThreads<O, B> {
+  fn new(
+    observer: MutArc<Option<O>>,
+    remaining: B,
+    resubscribe: Arc<dyn Fn(RetryObserverThreads<O, B>) + Send + Sync>,
+  ) -> Self {
+    Self { observer, remaining, resubscribe }
+  }
+}
+
+impl_retry!(
+  RetryOp<'a, S, B>,
+  RetryObserver<'a, O, B>,
+  MutRc,
+  Rc,
+  MultiSubscription<'a>,
+  BoxSubscription,
+  'a
+);
+impl_retry!(
+  RetryOpThreads<S, B>,
+  RetryObserverThreads<O, B>,
+  MutArc,
+  Arc,
+  MultiSubscriptionThreads,
+  BoxSubscriptionThreads,
+  ,
+  Send
+);
+
+/// The error of [`ObservableExt::retry_reporting`] once all the attempts
+/// failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryExhausted<Err> {
+  /// How many times the source was subscribed, the first one included.
+  pub attempts: usize,
+  /// The error of the last attempt.
+  pub last_error: Err,
+}
+
+impl<Err: fmt::Display> fmt::Display for RetryExhausted<Err> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "failed after {} attempts: {}",
+      self.attempts, self.last_error
+    )
+  }
+}
+
+impl<Err: fmt::Debug + fmt::Display> std::error::Error for RetryExhausted<Err> {}
+
+/// Wraps the error of a retry operator in a [`RetryExhausted`]. See
+/// [`ObservableExt::retry_reporting`].
+#[derive(Clone)]
+pub struct RetryReportingOp<S> {
+  pub(crate) source: S,
+  pub(crate) attempts: usize,
+}
+
+impl<Item, Err, O, S> Observable<Item, RetryExhausted<Err>, O>
+  for RetryReportingOp<S>
+where
+  O: Observer<Item, RetryExhausted<Err>>,
+  S: Observable<Item, Err, RetryReportingObserver<O>>,
+{
+  type Unsub = S::Unsub;
+
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    self.source.actual_subscribe(RetryReportingObserver {
+      observer,
+      attempts: self.attempts,
+    })
+  }
+}
+
+impl<Item, Err, S> ObservableExt<Item, RetryExhausted<Err>>
+  for RetryReportingOp<S>
+where
+  S: ObservableExt<Item, Err>,
+{
+}
+
+pub struct RetryReportingObserver<O> {
+  observer: O,
+  attempts: usize,
+}
+
+impl<Item, Err, O> Observer<Item, Err> for RetryReportingObserver<O>
+where
+  O: Observer<Item, RetryExhausted<Err>>,
+{
+  #[inline]
+  fn next(&mut self, value: Item) {
+    self.observer.next(value)
+  }
+
+  fn error(self, err: Err) {
+    // the retry operator only gives up once every attempt failed.
+    let attempts = self.attempts;
+    self
+      .observer
+      .error(RetryExhausted { attempts, last_error: err })
+  }
+
+  #[inline]
+  fn complete(self) {
+    self.observer.complete()
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.observer.is_finished()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::{
+    observable::fake_timer::FakeClock, ops::complete_status::CompleteStatus,
+    prelude::*,
+  };
+  use futures::executor::ThreadPool;
+  use std::{cell::Cell, rc::Rc, sync::Arc, time::Duration};
+
+  #[test]
+  fn resubscribe_on_error() {
+    let attempts = Rc::new(Cell::new(0));
+    let c_attempts = attempts.clone();
+    let mut values = vec![];
+    let mut error = None;
+    observable::create(move |mut subscriber: Subscriber<_>| {
+      c_attempts.set(c_attempts.get() + 1);
+      subscriber.next(c_attempts.get());
+      subscriber.error("failed");
+    })
+    .retry(2)
+    .on_error(|e| error = Some(e))
+    .subscribe(|v| values.push(v));
+
+    assert_eq!(attempts.get(), 3);
+    assert_eq!(values, vec![1, 2, 3]);
+    assert_eq!(error, Some("failed"));
+  }
+
+  #[test]
+  fn stop_retry_once_completed() {
+    let attempts = Rc::new(Cell::new(0));
+    let c_attempts = attempts.clone();
+    let mut completed = false;
+    observable::create(move |subscriber: Subscriber<_>| {
+      c_attempts.set(c_attempts.get() + 1);
+      if c_attempts.get() < 2 {
+        subscriber.error(());
+      } else {
+        subscriber.complete();
+      }
+    })
+    .retry(5)
+    .on_complete(|| completed = true)
+    .on_error(|_| {})
+    .subscribe(|_: i32| {});
+
+    assert_eq!(attempts.get(), 2);
+    assert!(completed);
+  }
+
+  #[test]
+  fn retain_scan_accumulator() {
+    let mut fresh = vec![];
+    observable::create(|mut subscriber: Subscriber<_>| {
+      subscriber.next(1);
+      subscriber.next(2);
+      subscriber.error(());
+    })
+    .scan_initial(0, |acc, v| acc + v)
+    .retry(2)
+    .on_error(|_| {})
+    .subscribe(|v| fresh.push(v));
+    assert_eq!(fresh, vec![1, 3, 1, 3, 1, 3]);
+
+    let mut retained = vec![];
+    observable::create(|mut subscriber: Subscriber<_>| {
+      subscriber.next(1);
+      subscriber.next(2);
+      subscriber.error(());
+    })
+    .scan_initial(0, |acc, v| acc + v)
+    .retain_on_resubscribe()
+    .retry(2)
+    .on_error(|_| {})
+    .subscribe(|v| retained.push(v));
+    assert_eq!(retained, vec![1, 3, 4, 6, 7, 9]);
+  }
+
+  #[test]
+  fn reset_operators_before_retry() {
+    let mut attempts = 0;
+    let mut buffers = vec![];
+    let mut sums = vec![];
+    fn emit<O: Observer<i32, ()>>(mut subscriber: Subscriber<O>) {
+      subscriber.next(1);
+      subscriber.next(2);
+      subscriber.next(3);
+      subscriber.error(());
+    }
+
+    // every attempt buffers from an empty buffer and scans from the seed.
+    observable::create(emit)
+      .buffer_with_count(2)
+      .retry(2)
+      .on_error(|_| {})
+      .subscribe(|v| {
+        attempts += 1;
+        buffers.push(v)
+      });
+    observable::create(emit)
+      .scan_initial(10, |acc, v| acc + v)
+      .retry(3)
+      .on_error(|_| {})
+      .subscribe(|v| sums.push(v));
+
+    assert_eq!(attempts, 3);
+    assert_eq!(buffers, vec![vec![1, 2], vec![1, 2], vec![1, 2]]);
+    assert_eq!(sums, [11, 13, 16].repeat(4));
+  }
+
+  #[test]
+  fn unsubscribe_retry() {
+    let mut values = vec![];
+    let mut subject = Subject::default();
+    let subscription = subject
+      .clone()
+      .retry(1)
+      .on_error(|_: ()| {})
+      .subscribe(|v| values.push(v));
+
+    subject.next(1);
+    subscription.unsubscribe();
+    subject.next(2);
+    drop(subject);
+
+    assert_eq!(values, vec![1]);
+  }
+
+  #[test]
+  fn retry_threads() {
+    let pool = FuturesThreadPoolScheduler::new().unwrap();
+    let (o, status) = observable::create(|mut s: SubscriberThreads<_>| {
+      s.next(1);
+      s.error(());
+    })
+    .subscribe_on(pool)
+    .retry_threads(3)
+    .complete_status();
+    let values = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+    let c_values = values.clone();
+    o.on_error(|_| {})
+      .subscribe(move |v| c_values.lock().unwrap().push(v));
+    CompleteStatus::wait_for_end(status);
+
+    assert_eq!(*values.lock().unwrap(), vec![1, 1, 1, 1]);
+  }
+
+  #[test]
+  fn report_attempts_on_exhausted() {
+    let mut error = None;
+    observable::create(|subscriber: Subscriber<_>| subscriber.error("timeout"))
+      .retry_reporting(2)
+      .on_error(|e| error = Some(e))
+      .subscribe(|_: i32| {});
+
+    let error = error.unwrap();
+    assert_eq!(
+      error,
+      super::RetryExhausted { attempts: 3, last_error: "timeout" }
+    );
+    assert_eq!(error.to_string(), "failed after 3 attempts: timeout");
+  }
+
+  #[test]
+  fn report_nothing_on_success() {
+    let attempts = Rc::new(Cell::new(0));
+    let c_attempts = attempts.clone();
+    let mut values = vec![];
+    let mut completed = false;
+    observable::create(move |mut subscriber: Subscriber<_>| {
+      c_attempts.set(c_attempts.get() + 1);
+      if c_attempts.get() < 2 {
+        subscriber.error("timeout");
+      } else {
+        subscriber.next(1);
+        subscriber.complete();
+      }
+    })
+    .retry_reporting(3)
+    .on_complete(|| completed = true)
+    .on_error(|_| unreachable!())
+    .subscribe(|v| values.push(v));
+
+    assert_eq!(values, vec![1]);
+    assert!(completed);
+  }
+
+  #[test]
+  fn retry_reporting_threads() {
+    use std::sync::Mutex;
+
+    let error = Arc::new(Mutex::new(None));
+    let c_error = error.clone();
+    observable::create(|subscriber: SubscriberThreads<_>| subscriber.error(()))
+      .retry_reporting_threads(1)
+      .on_error(move |e| *c_error.lock().unwrap() = Some(e.attempts))
+      .subscribe(|_: i32| {});
+
+    assert_eq!(*error.lock().unwrap(), Some(2));
+  }
+
+  #[test]
+  fn retry_windowed_exhausted() {
+    let attempts = Rc::new(Cell::new(0));
+    let c_attempts = attempts.clone();
+    let mut errors = 0;
+    observable::create(move |subscriber: Subscriber<_>| {
+      c_attempts.set(c_attempts.get() + 1);
+      subscriber.error("unavailable");
+    })
+    .retry_windowed(3, Duration::from_secs(60), FakeClock::default())
+    .on_error(|_| errors += 1)
+    .subscribe(|_: i32| {});
+
+    assert_eq!(attempts.get(), 4);
+    assert_eq!(errors, 1);
+  }
+
+  #[test]
+  fn retry_windowed_slides() {
+    let clock = FakeClock::default();
+    let c_clock = clock.clone();
+    let attempts = Rc::new(Cell::new(0));
+    let c_attempts = attempts.clone();
+    observable::create(move |subscriber: Subscriber<_>| {
+      c_attempts.set(c_attempts.get() + 1);
+      if c_attempts.get() == 2 {
+        // the first retry leaves the window meanwhile.
+        c_clock.advance(Duration::from_millis(30));
+      }
+      subscriber.error("unavailable");
+    })
+    .retry_windowed(1, Duration::from_millis(20), clock)
+    .on_error(|_| {})
+    .subscribe(|_: i32| {});
+
+    assert_eq!(attempts.get(), 3);
+  }
+
+  #[test]
+  fn retry_windowed_threads() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let c_attempts = attempts.clone();
+    observable::create(move |subscriber: SubscriberThreads<_>| {
+      c_attempts.fetch_add(1, Ordering::Relaxed);
+      subscriber.error(());
+    })
+    .retry_windowed_threads(
+      2,
+      Duration::from_secs(60),
+      ThreadPool::new().unwrap(),
+    )
+    .on_error(|_| {})
+    .subscribe(|_: i32| {});
+
+    assert_eq!(attempts.load(Ordering::Relaxed), 3);
+  }
+}
@@ -139,10 +139,125 @@ where
   }
 }
 
+/// Like [`SampleOp`], but re-emits the last value on every sampling even if
+/// the source hasn't emitted a new one since. See
+/// [`ObservableExt::hold_sample`].
+#[derive(Clone)]
+pub struct HoldSampleOp<Source, Sample, SampleItem, SampleErr> {
+  source: Source,
+  sample: Sample,
+  _hint: TypeHint<(SampleItem, SampleErr)>,
+}
+
+/// The thread safe version of [`HoldSampleOp`].
+#[derive(Clone)]
+pub struct HoldSampleOpThreads<Source, Sample, SampleItem, SampleErr> {
+  source: Source,
+  sample: Sample,
+  _hint: TypeHint<(SampleItem, SampleErr)>,
+}
+
+macro_rules! impl_hold_sample_op {
+  ($name: ident, $rc: ident) => {
+    impl<Source, Sample, SampleItem, SampleErr>
+      $name<Source, Sample, SampleItem, SampleErr>
+    {
+      #[inline]
+      pub(crate) fn new(source: Source, sample: Sample) -> Self {
+        Self {
+          source,
+          sample,
+          _hint: TypeHint::default(),
+        }
+      }
+    }
+
+    impl<Item, Err, SampleItem, SampleErr, Source, Sample, O>
+      Observable<Item, Err, O> for $name<Source, Sample, SampleItem, SampleErr>
+    where
+      O: Observer<Item, Err>,
+      Source: Observable<
+        Item,
+        Err,
+        SourceObserver<$rc<Option<O>>, $rc<Option<Item>>>,
+      >,
+      Sample: Observable<
+        SampleItem,
+        SampleErr,
+        HoldSampleObserver<$rc<Option<O>>, $rc<Option<Item>>, Err>,
+      >,
+      Item: Clone,
+    {
+      type Unsub = ZipSubscription<Source::Unsub, Sample::Unsub>;
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let value = $rc::own(None);
+        let observer = $rc::own(Some(observer));
+        let source_observer = SourceObserver {
+          observer: observer.clone(),
+          value: value.clone(),
+        };
+        let sample_observer = HoldSampleObserver {
+          observer,
+          value,
+          _hint: TypeHint::default(),
+        };
+
+        let source_unsub = self.source.actual_subscribe(source_observer);
+        let sample_unsub = self.sample.actual_subscribe(sample_observer);
+        ZipSubscription::new(source_unsub, sample_unsub)
+      }
+    }
+
+    impl<Item, Err, SampleItem, SampleErr, Source, Sample>
+      ObservableExt<Item, Err> for $name<Source, Sample, SampleItem, SampleErr>
+    where
+      Source: ObservableExt<Item, Err>,
+      Sample: ObservableExt<SampleItem, SampleErr>,
+    {
+    }
+  };
+}
+
+impl_hold_sample_op!(HoldSampleOp, MutRc);
+impl_hold_sample_op!(HoldSampleOpThreads, MutArc);
+
+pub struct HoldSampleObserver<O, V, Err> {
+  observer: O,
+  value: V,
+  _hint: TypeHint<Err>,
+}
+
+impl<Item, SampleItem, SampleErr, Err, O, V> Observer<SampleItem, SampleErr>
+  for HoldSampleObserver<O, V, Err>
+where
+  O: Observer<Item, Err>,
+  V: RcDerefMut<Target = Option<Item>>,
+  Item: Clone,
+{
+  fn next(&mut self, _: SampleItem) {
+    // keep the value, it's emitted again if nothing new arrives.
+    let item = self.value.rc_deref_mut().clone();
+    if let Some(item) = item {
+      self.observer.next(item)
+    }
+  }
+
+  #[inline]
+  fn error(self, _: SampleErr) {}
+
+  #[inline]
+  fn complete(self) {}
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.observer.is_finished()
+  }
+}
+
 #[cfg(test)]
 mod test {
   use crate::{observable::fake_timer::FakeClock, prelude::*};
-  use std::{cell::RefCell, rc::Rc, time::Duration};
+  use std::{cell::RefCell, convert::Infallible, rc::Rc, time::Duration};
 
   #[test]
   fn sample_base() {
@@ -196,4 +311,158 @@ mod test {
     notifier.complete();
     assert_eq!(*test_code.rc_deref(), 4);
   }
+
+  #[test]
+  fn hold_last_value_across_ticks() {
+    use super::HoldSampleOp;
+
+    let clock = FakeClock::default();
+    let x = Rc::new(RefCell::new(vec![]));
+    let c_x = x.clone();
+    let mut subject = Subject::<_, Infallible>::default();
+
+    HoldSampleOp::new(
+      subject.clone(),
+      clock.interval(Duration::from_millis(10)),
+    )
+    .subscribe(move |v| c_x.borrow_mut().push(v));
+
+    // nothing to hold yet.
+    clock.advance(Duration::from_millis(11));
+    assert!(x.borrow().is_empty());
+
+    subject.next(1);
+    clock.advance(Duration::from_millis(30));
+    assert_eq!(&*x.borrow(), &[1, 1, 1]);
+
+    subject.next(2);
+    subject.next(3);
+    clock.advance(Duration::from_millis(10));
+    assert_eq!(&*x.borrow(), &[1, 1, 1, 3]);
+  }
+
+  #[test]
+  fn hold_sample_repeat_latest_value() {
+    use futures::executor::LocalPool;
+
+    let mut pool = LocalPool::new();
+    let x = Rc::new(RefCell::new(vec![]));
+    let c_x = x.clone();
+    let mut source = Subject::<_, Infallible>::default();
+    source
+      .clone()
+      .hold_sample(Duration::from_millis(1), pool.spawner())
+      .take(3)
+      .subscribe(move |v| c_x.borrow_mut().push(v));
+
+    source.next(1);
+    pool.run();
+    assert_eq!(&*x.borrow(), &[1, 1, 1]);
+  }
+
+  #[test]
+  fn hold_sample_threads_repeat_latest_value() {
+    use crate::ops::complete_status::CompleteStatus;
+    use std::sync::{Arc, Mutex};
+
+    let x = Arc::new(Mutex::new(vec![]));
+    let c_x = x.clone();
+    let mut source = SubjectThreads::<_, Infallible>::default();
+    let pool = FuturesThreadPoolScheduler::new().unwrap();
+    let (o, status) = source
+      .clone()
+      .hold_sample_threads(Duration::from_millis(1), pool)
+      .take(3)
+      .complete_status();
+    o.subscribe(move |v| c_x.lock().unwrap().push(v));
+
+    source.next(7);
+    CompleteStatus::wait_for_end(status);
+    assert_eq!(*x.lock().unwrap(), vec![7, 7, 7]);
+  }
+
+  #[test]
+  fn hold_sample_stop_with_source() {
+    use super::HoldSampleOp;
+
+    let clock = FakeClock::default();
+    let x = Rc::new(RefCell::new(vec![]));
+    let c_x = x.clone();
+    let completed = Rc::new(RefCell::new(false));
+    let c_completed = completed.clone();
+    let mut subject = Subject::<_, &str>::default();
+
+    HoldSampleOp::new(
+      subject.clone(),
+      clock.interval(Duration::from_millis(10)),
+    )
+    .on_complete(move || *c_completed.borrow_mut() = true)
+    .on_error(|_| {})
+    .subscribe(move |v| c_x.borrow_mut().push(v));
+
+    subject.next(1);
+    clock.advance(Duration::from_millis(11));
+    subject.clone().complete();
+    clock.advance(Duration::from_millis(30));
+
+    assert_eq!(&*x.borrow(), &[1]);
+    assert!(*completed.borrow());
+  }
+
+  #[test]
+  fn aligned_samplers_tick_together() {
+    let clock = FakeClock::default();
+    let origin = clock.current_time();
+    let period = Duration::from_millis(100);
+    let snapshots = Rc::new(RefCell::new(vec![]));
+    let mut cpu = Subject::<_, Infallible>::default();
+    let mut memory = Subject::<_, Infallible>::default();
+
+    let (c_snapshots, c_clock) = (snapshots.clone(), clock.clone());
+    cpu
+      .clone()
+      .sample_aligned(period, clock.clone())
+      .subscribe(move |v| {
+        c_snapshots
+          .borrow_mut()
+          .push((c_clock.current_time(), "cpu", v))
+      });
+    // subscribed in the middle of a period.
+    clock.advance(Duration::from_millis(30));
+    let (c_snapshots, c_clock) = (snapshots.clone(), clock.clone());
+    memory
+      .clone()
+      .sample_aligned(period, clock.clone())
+      .subscribe(move |v| {
+        c_snapshots
+          .borrow_mut()
+          .push((c_clock.current_time(), "memory", v))
+      });
+
+    for i in 0..40 {
+      cpu.next(i);
+      memory.next(i);
+      clock.advance(Duration::from_millis(10));
+    }
+
+    let snapshots = snapshots.borrow();
+    let cpu_ticks: Vec<_> = snapshots
+      .iter()
+      .filter(|(_, name, _)| *name == "cpu")
+      .map(|(at, _, v)| (*at, *v))
+      .collect();
+    let memory_ticks: Vec<_> = snapshots
+      .iter()
+      .filter(|(_, name, _)| *name == "memory")
+      .map(|(at, _, v)| (*at, *v))
+      .collect();
+    // a tick is due once the clock passed it, after the value of that time.
+    let ticks: Vec<_> = [7, 17, 27, 37]
+      .into_iter()
+      .enumerate()
+      .map(|(i, v)| (origin + period * (i as u32 + 1), v))
+      .collect();
+    assert_eq!(cpu_ticks, ticks);
+    assert_eq!(memory_ticks, ticks);
+  }
 }
@@ -1,10 +1,14 @@
-use crate::prelude::*;
+use crate::{
+  prelude::*,
+  rc::{MutArc, MutRc, RcDeref, RcDerefMut},
+};
 
 #[derive(Clone)]
 pub struct ScanOp<Source, BinaryOp, OutputItem, InputItem> {
   source: Source,
   binary_op: BinaryOp,
   initial_value: OutputItem,
+  retained: Option<MutArc<OutputItem>>,
   _m: TypeHint<InputItem>,
 }
 
@@ -20,15 +24,60 @@ impl<Source, BinaryOp, OutputItem, InputItem>
       source,
       binary_op,
       initial_value,
+      retained: None,
       _m: TypeHint::default(),
     }
   }
+
+  /// Keep the accumulator alive across resubscriptions.
+  ///
+  /// By default every subscription starts to accumulate from the initial
+  /// value. After calling this method, the op and all its clones share one
+  /// accumulator cell:
+  ///
+  /// - a subscription starts from the value in the cell, which is the initial
+  ///   value until anything has been accumulated;
+  /// - every accumulated value is written back to the cell before it is
+  ///   emitted.
+  ///
+  /// So a resubscription, for example by `retry`, continues from the last
+  /// value the previous subscription emitted instead of starting over. The
+  /// cell is shared, not reset, so subscribing to clones concurrently makes
+  /// them interleave their accumulation.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use rxrust::prelude::*;
+  ///
+  /// let mut totals = vec![];
+  /// observable::create(|mut subscriber: Subscriber<_>| {
+  ///   subscriber.next(1);
+  ///   subscriber.next(2);
+  ///   subscriber.error("connection lost");
+  /// })
+  /// .scan_initial(0, |acc, v| acc + v)
+  /// .retain_on_resubscribe()
+  /// .retry(1)
+  /// .on_error(|_| {})
+  /// .subscribe(|v| totals.push(v));
+  ///
+  /// assert_eq!(totals, vec![1, 3, 4, 6]);
+  /// ```
+  pub fn retain_on_resubscribe(mut self) -> Self
+  where
+    OutputItem: Clone,
+  {
+    self.retained = Some(MutArc::own(self.initial_value.clone()));
+    self
+  }
 }
 
 pub struct ScanObserver<Observer, BinaryOp, OutputItem> {
   target_observer: Observer,
   binary_op: BinaryOp,
   acc: OutputItem,
+  retained: Option<MutArc<OutputItem>>,
 }
 
 impl<InputItem, OutputItem, Err, O, S, BinaryOp> Observable<OutputItem, Err, O>
@@ -41,10 +90,15 @@ where
 {
   type Unsub = S::Unsub;
   fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    let acc = match &self.retained {
+      Some(retained) => retained.rc_deref().clone(),
+      None => self.initial_value,
+    };
     self.source.actual_subscribe(ScanObserver {
       target_observer: observer,
       binary_op: self.binary_op,
-      acc: self.initial_value,
+      acc,
+      retained: self.retained,
     })
   }
 }
@@ -69,6 +123,9 @@ where
   fn next(&mut self, value: InputItem) {
     // accumulating each item with a current value
     self.acc = (self.binary_op)(self.acc.clone(), value);
+    if let Some(retained) = &self.retained {
+      *retained.rc_deref_mut() = self.acc.clone();
+    }
     self.target_observer.next(self.acc.clone())
   }
 
@@ -88,9 +145,278 @@ where
   }
 }
 
+/// A `scan` seeded by the first item of the source, see
+/// [`ObservableExt::scan_from_first`].
+#[derive(Clone)]
+pub struct ScanFromFirstOp<Source, BinaryOp> {
+  pub(crate) source: Source,
+  pub(crate) binary_op: BinaryOp,
+}
+
+pub struct ScanFromFirstObserver<O, BinaryOp, Item> {
+  target_observer: O,
+  binary_op: BinaryOp,
+  acc: Option<Item>,
+}
+
+impl<Item, Err, O, S, BinaryOp> Observable<Item, Err, O>
+  for ScanFromFirstOp<S, BinaryOp>
+where
+  S: Observable<Item, Err, ScanFromFirstObserver<O, BinaryOp, Item>>,
+  O: Observer<Item, Err>,
+  BinaryOp: FnMut(Item, Item) -> Item,
+  Item: Clone,
+{
+  type Unsub = S::Unsub;
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    self.source.actual_subscribe(ScanFromFirstObserver {
+      target_observer: observer,
+      binary_op: self.binary_op,
+      acc: None,
+    })
+  }
+}
+
+impl<Item, Err, S, BinaryOp> ObservableExt<Item, Err>
+  for ScanFromFirstOp<S, BinaryOp>
+where
+  S: ObservableExt<Item, Err>,
+{
+}
+
+impl<Item, Err, O, BinaryOp> Observer<Item, Err>
+  for ScanFromFirstObserver<O, BinaryOp, Item>
+where
+  O: Observer<Item, Err>,
+  BinaryOp: FnMut(Item, Item) -> Item,
+  Item: Clone,
+{
+  fn next(&mut self, value: Item) {
+    // the first item is the seed, and passes through unchanged.
+    let acc = match self.acc.take() {
+      Some(acc) => (self.binary_op)(acc, value),
+      None => value,
+    };
+    self.acc = Some(acc.clone());
+    self.target_observer.next(acc)
+  }
+
+  #[inline]
+  fn error(self, err: Err) {
+    self.target_observer.error(err)
+  }
+
+  #[inline]
+  fn complete(self) {
+    self.target_observer.complete()
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.target_observer.is_finished()
+  }
+}
+
+/// A `scan` that restarts from the seed every time the notifier emits, see
+/// [`ObservableExt::scan_reset`].
+#[derive(Clone)]
+pub struct ScanResetOp<
+  S,
+  N,
+  BinaryOp,
+  OutputItem,
+  InputItem,
+  NotifyItem,
+  NotifyErr,
+> {
+  source: S,
+  notifier: N,
+  binary_op: BinaryOp,
+  seed: OutputItem,
+  _hint: TypeHint<(InputItem, NotifyItem, NotifyErr)>,
+}
+
+/// The thread safe version of [`ScanResetOp`].
+#[derive(Clone)]
+pub struct ScanResetOpThreads<
+  S,
+  N,
+  BinaryOp,
+  OutputItem,
+  InputItem,
+  NotifyItem,
+  NotifyErr,
+> {
+  source: S,
+  notifier: N,
+  binary_op: BinaryOp,
+  seed: OutputItem,
+  _hint: TypeHint<(InputItem, NotifyItem, NotifyErr)>,
+}
+
+pub struct ScanResetObserver<O, BinaryOp, Acc> {
+  target_observer: O,
+  binary_op: BinaryOp,
+  acc: Acc,
+}
+
+pub struct ScanResetNotifierObserver<O, Acc, OutputItem, Err> {
+  target_observer: O,
+  acc: Acc,
+  seed: OutputItem,
+  _hint: TypeHint<Err>,
+}
+
+macro_rules! impl_scan_reset {
+  ($name: ident, $rc: ident) => {
+    impl<S, N, BinaryOp, OutputItem, InputItem, NotifyItem, NotifyErr>
+      $name<S, N, BinaryOp, OutputItem, InputItem, NotifyItem, NotifyErr>
+    {
+      #[inline]
+      pub(crate) fn new(
+        source: S,
+        seed: OutputItem,
+        binary_op: BinaryOp,
+        notifier: N,
+      ) -> Self {
+        Self {
+          source,
+          notifier,
+          binary_op,
+          seed,
+          _hint: TypeHint::default(),
+        }
+      }
+    }
+
+    impl<
+        S,
+        N,
+        BinaryOp,
+        OutputItem,
+        InputItem,
+        NotifyItem,
+        NotifyErr,
+        Err,
+        O,
+      > Observable<OutputItem, Err, O>
+      for $name<S, N, BinaryOp, OutputItem, InputItem, NotifyItem, NotifyErr>
+    where
+      O: Observer<OutputItem, Err>,
+      S: Observable<
+        InputItem,
+        Err,
+        ScanResetObserver<$rc<Option<O>>, BinaryOp, $rc<OutputItem>>,
+      >,
+      N: Observable<
+        NotifyItem,
+        NotifyErr,
+        ScanResetNotifierObserver<
+          $rc<Option<O>>,
+          $rc<OutputItem>,
+          OutputItem,
+          Err,
+        >,
+      >,
+      BinaryOp: FnMut(OutputItem, InputItem) -> OutputItem,
+      OutputItem: Clone,
+    {
+      type Unsub = ZipSubscription<S::Unsub, N::Unsub>;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let target_observer = $rc::own(Some(observer));
+        let acc = $rc::own(self.seed.clone());
+        let a = self.source.actual_subscribe(ScanResetObserver {
+          target_observer: target_observer.clone(),
+          binary_op: self.binary_op,
+          acc: acc.clone(),
+        });
+        let b = self.notifier.actual_subscribe(ScanResetNotifierObserver {
+          target_observer,
+          acc,
+          seed: self.seed,
+          _hint: TypeHint::default(),
+        });
+        ZipSubscription::new(a, b)
+      }
+    }
+
+    impl<S, N, BinaryOp, OutputItem, InputItem, NotifyItem, NotifyErr, Err>
+      ObservableExt<OutputItem, Err>
+      for $name<S, N, BinaryOp, OutputItem, InputItem, NotifyItem, NotifyErr>
+    where
+      S: ObservableExt<InputItem, Err>,
+      N: ObservableExt<NotifyItem, NotifyErr>,
+    {
+    }
+  };
+}
+
+impl_scan_reset!(ScanResetOp, MutRc);
+impl_scan_reset!(ScanResetOpThreads, MutArc);
+
+impl<InputItem, OutputItem, Err, O, BinaryOp, Acc> Observer<InputItem, Err>
+  for ScanResetObserver<O, BinaryOp, Acc>
+where
+  O: Observer<OutputItem, Err>,
+  BinaryOp: FnMut(OutputItem, InputItem) -> OutputItem,
+  Acc: RcDerefMut<Target = OutputItem>,
+  OutputItem: Clone,
+{
+  fn next(&mut self, value: InputItem) {
+    let acc = {
+      let mut acc = self.acc.rc_deref_mut();
+      *acc = (self.binary_op)(acc.clone(), value);
+      acc.clone()
+    };
+    self.target_observer.next(acc)
+  }
+
+  #[inline]
+  fn error(self, err: Err) {
+    self.target_observer.error(err)
+  }
+
+  #[inline]
+  fn complete(self) {
+    self.target_observer.complete()
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.target_observer.is_finished()
+  }
+}
+
+impl<NotifyItem, NotifyErr, OutputItem, Err, O, Acc>
+  Observer<NotifyItem, NotifyErr>
+  for ScanResetNotifierObserver<O, Acc, OutputItem, Err>
+where
+  O: Observer<OutputItem, Err>,
+  Acc: RcDerefMut<Target = OutputItem>,
+  OutputItem: Clone,
+{
+  fn next(&mut self, _: NotifyItem) {
+    *self.acc.rc_deref_mut() = self.seed.clone();
+    self.target_observer.next(self.seed.clone())
+  }
+
+  #[inline]
+  fn error(self, _: NotifyErr) {}
+
+  #[inline]
+  fn complete(self) {}
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.target_observer.is_finished()
+  }
+}
+
 #[cfg(test)]
 mod test {
   use crate::prelude::*;
+  use std::convert::Infallible;
 
   #[test]
   fn scan_initial() {
@@ -144,6 +470,30 @@ mod test {
     m.scan(|_acc, v| v as f32).subscribe(|_| {});
   }
 
+  #[test]
+  fn scan_from_first_running_max() {
+    let mut emitted = vec![];
+    observable::from_iter([3, 1, 4, 1, 5, 9, 2])
+      .scan_from_first(|acc: i32, v| acc.max(v))
+      .subscribe(|v| emitted.push(v));
+
+    assert_eq!(emitted, vec![3, 3, 4, 4, 5, 9, 9]);
+  }
+
+  #[test]
+  fn scan_from_first_reseed_per_subscription() {
+    let o = observable::from_iter(["a", "b"])
+      .map(String::from)
+      .scan_from_first(|acc, v| acc + &v);
+    let mut first = vec![];
+    let mut second = vec![];
+    o.clone().subscribe(|v| first.push(v));
+    o.subscribe(|v| second.push(v));
+
+    assert_eq!(first, vec!["a", "ab"]);
+    assert_eq!(second, first);
+  }
+
   #[test]
   fn bench() {
     do_bench();
@@ -154,4 +504,60 @@ mod test {
   fn bench_scan(b: &mut bencher::Bencher) {
     b.iter(scan_initial);
   }
+
+  #[test]
+  fn scan_reset_on_notifier() {
+    let mut emitted = vec![];
+    let mut source = Subject::<i32, Infallible>::default();
+    let mut new_period = Subject::<(), Infallible>::default();
+
+    source
+      .clone()
+      .scan_reset(0, |acc, v| acc + v, new_period.clone())
+      .subscribe(|v| emitted.push(v));
+
+    source.next(1);
+    source.next(2);
+    new_period.next(());
+    source.next(3);
+    source.next(4);
+    drop((source, new_period));
+
+    assert_eq!(emitted, vec![1, 3, 0, 3, 7]);
+  }
+
+  #[test]
+  fn scan_reset_completes_with_source() {
+    let mut completed = false;
+    let new_period = Subject::<(), Infallible>::default();
+
+    observable::from_iter([1, 2])
+      .scan_reset(0, |acc, v| acc + v, new_period.clone())
+      .on_complete(|| completed = true)
+      .subscribe(|_| {});
+    drop(new_period);
+
+    assert!(completed);
+  }
+
+  #[test]
+  fn scan_reset_threads() {
+    use crate::rc::{MutArc, RcDeref, RcDerefMut};
+
+    let emitted = MutArc::own(vec![]);
+    let c_emitted = emitted.clone();
+    let mut source = SubjectThreads::<i32, Infallible>::default();
+    let mut new_period = SubjectThreads::<(), Infallible>::default();
+
+    source
+      .clone()
+      .scan_reset_threads(10, |acc, v| acc + v, new_period.clone())
+      .subscribe(move |v| c_emitted.rc_deref_mut().push(v));
+
+    source.next(1);
+    new_period.next(());
+    source.next(2);
+
+    assert_eq!(*emitted.rc_deref(), vec![11, 10, 12]);
+  }
 }
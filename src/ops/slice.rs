@@ -0,0 +1,154 @@
+use crate::prelude::*;
+
+/// Emits only the items whose index is in `[start, end)`. See
+/// [`ObservableExt::slice`].
+#[derive(Clone)]
+pub struct SliceOp<S> {
+  source: S,
+  start: usize,
+  end: usize,
+}
+
+impl<S> SliceOp<S> {
+  #[inline]
+  pub fn new(source: S, start: usize, end: usize) -> Self {
+    assert!(start <= end, "`start` must not be greater than `end`");
+    SliceOp { source, start, end }
+  }
+}
+
+impl<Item, Err, O, S> Observable<Item, Err, O> for SliceOp<S>
+where
+  O: Observer<Item, Err>,
+  S: Observable<Item, Err, SliceObserver<O>>,
+{
+  type Unsub = S::Unsub;
+
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    let observer = if self.start == self.end {
+      // an empty range, nothing to wait for.
+      observer.complete();
+      None
+    } else {
+      Some(observer)
+    };
+    self.source.actual_subscribe(SliceObserver {
+      observer,
+      start: self.start,
+      end: self.end,
+      index: 0,
+    })
+  }
+}
+
+impl<Item, Err, S> ObservableExt<Item, Err> for SliceOp<S> where
+  S: ObservableExt<Item, Err>
+{
+}
+
+pub struct SliceObserver<O> {
+  observer: Option<O>,
+  start: usize,
+  end: usize,
+  index: usize,
+}
+
+impl<Item, Err, O> Observer<Item, Err> for SliceObserver<O>
+where
+  O: Observer<Item, Err>,
+{
+  fn next(&mut self, value: Item) {
+    let Some(observer) = self.observer.as_mut() else {
+      return;
+    };
+    let index = self.index;
+    self.index += 1;
+    if index >= self.start {
+      observer.next(value);
+      if self.index == self.end {
+        self.observer.take().unwrap().complete()
+      }
+    }
+  }
+
+  #[inline]
+  fn error(mut self, err: Err) {
+    if let Some(observer) = self.observer.take() {
+      observer.error(err)
+    }
+  }
+
+  #[inline]
+  fn complete(mut self) {
+    if let Some(observer) = self.observer.take() {
+      observer.complete()
+    }
+  }
+
+  fn is_finished(&self) -> bool {
+    self.observer.as_ref().is_none_or(|o| o.is_finished())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::{cell::Cell, rc::Rc};
+
+  #[test]
+  fn slice_range() {
+    let mut values = vec![];
+    let mut completed = false;
+    observable::from_iter(0..100)
+      .slice(10, 20)
+      .on_complete(|| completed = true)
+      .subscribe(|v| values.push(v));
+
+    assert_eq!(values, (10..20).collect::<Vec<_>>());
+    assert!(completed);
+  }
+
+  #[test]
+  fn complete_at_end_boundary() {
+    let completed = Rc::new(Cell::new(false));
+    let c_completed = completed.clone();
+    let mut subject = Subject::default();
+    subject
+      .clone()
+      .slice(1, 3)
+      .on_complete(move || c_completed.set(true))
+      .subscribe(|_: i32| {});
+
+    subject.next(0);
+    subject.next(1);
+    assert!(!completed.get());
+    subject.next(2);
+    assert!(completed.get());
+  }
+
+  #[test]
+  fn source_shorter_than_range() {
+    let mut values = vec![];
+    let mut completed = false;
+    observable::from_iter(0..5)
+      .slice(3, 10)
+      .on_complete(|| completed = true)
+      .subscribe(|v| values.push(v));
+
+    assert_eq!(values, vec![3, 4]);
+    assert!(completed);
+  }
+
+  #[test]
+  fn empty_range() {
+    let mut hits = 0;
+    let mut completed = false;
+    observable::from_iter(0..5)
+      .slice(2, 2)
+      .on_complete(|| completed = true)
+      .subscribe(|_| hits += 1);
+
+    assert_eq!(hits, 0);
+    assert!(completed);
+  }
+}
@@ -1,11 +1,20 @@
 use std::{
   cell::RefCell,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
   task::{Context, Poll},
+  thread::{self, Thread},
 };
 
 use futures::{
-  channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
-  ready, FutureExt, Stream, StreamExt,
+  channel::mpsc::{
+    channel, unbounded, Receiver, Sender, UnboundedReceiver, UnboundedSender,
+  },
+  ready,
+  task::{waker, ArcWake},
+  FutureExt, Stream, StreamExt,
 };
 
 use crate::{observable::Observable, observer::Observer};
@@ -89,10 +98,149 @@ impl<T, E> Observer<T, E> for ObservableStreamObserver<T, E> {
   }
 }
 
+/// What a bounded stream does when the consumer lags and its buffer is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+  /// Block the thread emitting the value until the consumer makes room.
+  Block,
+  /// Stop the stream: the buffered values are still delivered, followed by
+  /// a `BoundedStreamError::Overflow` error.
+  Error,
+}
+
+/// The error of a stream created by
+/// [`ObservableExt::to_stream_bounded`](crate::observable::ObservableExt::to_stream_bounded).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BoundedStreamError<E> {
+  /// The buffer was full with the `OverflowPolicy::Error` policy.
+  Overflow,
+  /// The observable emitted an error.
+  Error(E),
+}
+
+type BoundedMessage<T, E> = Result<T, BoundedStreamError<E>>;
+
+struct ThreadWaker(Thread);
+
+impl ArcWake for ThreadWaker {
+  fn wake_by_ref(arc_self: &Arc<Self>) {
+    arc_self.0.unpark();
+  }
+}
+
+/// A stream that returns the values emitted by an observable, buffering at
+/// most `capacity` values.
+pub struct BoundedObservableStream<T, E> {
+  receiver: Receiver<BoundedMessage<T, E>>,
+  overflowed: Arc<AtomicBool>,
+}
+
+impl<T, E> BoundedObservableStream<T, E> {
+  /// Constructs a new `BoundedObservableStream<T, E>` that emits the values
+  /// from the observable, and applies `policy` when `capacity` values are
+  /// waiting to be consumed.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `capacity` is zero.
+  pub fn new<O>(observable: O, capacity: usize, policy: OverflowPolicy) -> Self
+  where
+    O: Observable<T, E, BoundedStreamObserver<T, E>>,
+  {
+    assert!(capacity > 0, "`capacity` must be greater than zero");
+    // the channel has one more slot for every sender.
+    let (sender, receiver) = channel(capacity - 1);
+    let overflowed = Arc::new(AtomicBool::new(false));
+    observable.actual_subscribe(BoundedStreamObserver {
+      sender,
+      policy,
+      overflowed: overflowed.clone(),
+    });
+
+    BoundedObservableStream { receiver, overflowed }
+  }
+}
+
+impl<T, E> Stream for BoundedObservableStream<T, E> {
+  type Item = BoundedMessage<T, E>;
+
+  fn poll_next(
+    mut self: std::pin::Pin<&mut Self>,
+    cx: &mut Context<'_>,
+  ) -> Poll<Option<Self::Item>> {
+    match ready!(self.receiver.poll_next_unpin(cx)) {
+      Some(msg) => Poll::Ready(Some(msg)),
+      // The channel is closed and drained, report the overflow once.
+      None if self.overflowed.swap(false, Ordering::AcqRel) => {
+        Poll::Ready(Some(Err(BoundedStreamError::Overflow)))
+      }
+      None => Poll::Ready(None),
+    }
+  }
+}
+
+pub struct BoundedStreamObserver<T, E> {
+  sender: Sender<BoundedMessage<T, E>>,
+  policy: OverflowPolicy,
+  overflowed: Arc<AtomicBool>,
+}
+
+impl<T, E> BoundedStreamObserver<T, E> {
+  fn send(&mut self, msg: BoundedMessage<T, E>) {
+    match self.policy {
+      OverflowPolicy::Block => {
+        // Park the thread until the consumer makes room. Not `block_on`, the
+        // source may emit inside an executor.
+        let waker = waker(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+          match self.sender.poll_ready(&mut cx) {
+            Poll::Ready(Ok(())) => {
+              let _ = self.sender.start_send(msg);
+              break;
+            }
+            // The stream is dropped, nothing to do.
+            Poll::Ready(Err(_)) => break,
+            Poll::Pending => thread::park(),
+          }
+        }
+      }
+      OverflowPolicy::Error => {
+        if let Err(e) = self.sender.try_send(msg) {
+          if e.is_full() {
+            self.overflowed.store(true, Ordering::Release);
+            self.sender.close_channel();
+          }
+        }
+      }
+    }
+  }
+}
+
+impl<T, E> Observer<T, E> for BoundedStreamObserver<T, E> {
+  fn next(&mut self, value: T) {
+    self.send(Ok(value));
+  }
+
+  fn error(mut self, err: E) {
+    self.send(Err(BoundedStreamError::Error(err)));
+  }
+
+  fn complete(self) {
+    // dropping the sender closes the channel, the stream ends once drained.
+  }
+
+  fn is_finished(&self) -> bool {
+    self.sender.is_closed()
+  }
+}
+
 #[cfg(test)]
 mod tests {
-  use crate::observable::ObservableExt;
-  use futures::StreamExt;
+  use super::{BoundedStreamError, OverflowPolicy};
+  use crate::{observable::ObservableExt, prelude::*};
+  use futures::{executor::block_on, StreamExt};
+  use std::convert::Infallible;
 
   #[tokio::test]
   async fn to_stream_receive_all_values_test() {
@@ -117,4 +265,61 @@ mod tests {
 
     assert!(values.is_empty());
   }
+
+  #[test]
+  fn to_stream_bounded_overflow_error() {
+    let stream = crate::observable::from_iter(0..10)
+      .to_stream_bounded(3, OverflowPolicy::Error);
+
+    let values = block_on(stream.collect::<Vec<_>>());
+    assert_eq!(
+      values,
+      vec![Ok(0), Ok(1), Ok(2), Err(BoundedStreamError::Overflow)]
+    );
+  }
+
+  #[test]
+  fn to_stream_bounded_forward_error() {
+    let stream = crate::observable::throw("oops")
+      .to_stream_bounded(1, OverflowPolicy::Error);
+
+    let values: Vec<Result<(), _>> = block_on(stream.collect());
+    assert_eq!(values, vec![Err(BoundedStreamError::Error("oops"))]);
+  }
+
+  #[test]
+  fn to_stream_bounded_block_source() {
+    use std::sync::{mpsc, Arc, Mutex};
+
+    let emitted = Arc::new(Mutex::new(0));
+    let c_emitted = emitted.clone();
+    let (reached, wait_reached) = mpsc::channel();
+    let reached = Mutex::new(reached);
+    let pool = FuturesThreadPoolScheduler::new().unwrap();
+    let mut stream = crate::observable::from_iter(0..100)
+      .tap(move |_| {
+        let mut emitted = c_emitted.lock().unwrap();
+        *emitted += 1;
+        // the buffer is full, this value blocks the source.
+        if *emitted == 5 {
+          reached.lock().unwrap().send(()).unwrap();
+        }
+      })
+      .subscribe_on(pool)
+      .to_stream_bounded(4, OverflowPolicy::Block);
+
+    // nothing is consumed until the source is blocked, the source can't emit
+    // another value before the blocked one is sent.
+    wait_reached.recv().unwrap();
+    assert_eq!(*emitted.lock().unwrap(), 5);
+
+    let values = block_on(stream.by_ref().take(5).collect::<Vec<_>>());
+    let expected: Vec<Result<_, BoundedStreamError<Infallible>>> =
+      (0..5).map(Ok).collect();
+    assert_eq!(values, expected);
+
+    let rest = block_on(stream.collect::<Vec<_>>());
+    assert_eq!(rest.len(), 95);
+    assert_eq!(*emitted.lock().unwrap(), 100);
+  }
 }
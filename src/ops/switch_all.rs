@@ -0,0 +1,412 @@
+use crate::{
+  prelude::*,
+  rc::{MutArc, MutRc, RcDeref, RcDerefMut},
+};
+
+/// Subscribes to each inner observable emitted by the source, unsubscribing
+/// the previous one, so only the most recent inner observable emits. See
+/// [`ObservableExt::switch_all`].
+pub struct SwitchAllOp<'a, S, ObservableItem> {
+  source: S,
+  _marker: TypeHint<&'a ObservableItem>,
+}
+
+/// The thread safe version of [`SwitchAllOp`].
+pub struct SwitchAllOpThreads<S, ObservableItem> {
+  source: S,
+  _marker: TypeHint<ObservableItem>,
+}
+
+impl<'a, S: Clone, ObservableItem> Clone
+  for SwitchAllOp<'a, S, ObservableItem>
+{
+  fn clone(&self) -> Self {
+    Self::new(self.source.clone())
+  }
+}
+
+impl<S: Clone, ObservableItem> Clone for SwitchAllOpThreads<S, ObservableItem> {
+  fn clone(&self) -> Self {
+    Self::new(self.source.clone())
+  }
+}
+
+impl<'a, S, ObservableItem> SwitchAllOp<'a, S, ObservableItem> {
+  #[inline]
+  pub(crate) fn new(source: S) -> Self {
+    Self { source, _marker: TypeHint::default() }
+  }
+}
+
+impl<S, ObservableItem> SwitchAllOpThreads<S, ObservableItem> {
+  #[inline]
+  pub(crate) fn new(source: S) -> Self {
+    Self { source, _marker: TypeHint::default() }
+  }
+}
+
+struct SwitchData<O> {
+  observer: O,
+  // the id of the inner observable allowed to emit.
+  active: usize,
+  inner_running: bool,
+  outside_completed: bool,
+}
+
+pub struct OutsideObserver<'a, O, Item> {
+  data: MutRc<Option<SwitchData<O>>>,
+  inner_unsub: MutRc<Option<BoxSubscription<'a>>>,
+  _hint: TypeHint<Item>,
+}
+
+pub struct OutsideObserverThreads<O, Item> {
+  data: MutArc<Option<SwitchData<O>>>,
+  inner_unsub: MutArc<Option<BoxSubscriptionThreads>>,
+  _hint: TypeHint<Item>,
+}
+
+pub struct InnerObserver<O> {
+  data: MutRc<Option<SwitchData<O>>>,
+  id: usize,
+}
+
+pub struct InnerObserverThreads<O> {
+  data: MutArc<Option<SwitchData<O>>>,
+  id: usize,
+}
+
+macro_rules! impl_switch_all {
+  ($op: ty, $outside: ident, $inner: ident, $rc: ident, $box_unsub: ty,
+    $($lf: lifetime)? $(,$send: ident)?) => {
+    impl<$($lf,)? ObservableItem, Item, Err, O, S> Observable<Item, Err, O>
+      for $op
+    where
+      O: Observer<Item, Err> $(+ $lf)? $(+ $send + 'static)?,
+      S: Observable<ObservableItem, Err, $outside<$($lf,)? O, Item>>,
+      ObservableItem: Observable<Item, Err, $inner<O>>,
+      ObservableItem::Unsub: $($lf)? $($send + 'static)?,
+    {
+      type Unsub = ZipSubscription<$rc<Option<$box_unsub>>, S::Unsub>;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let data = SwitchData {
+          observer,
+          active: 0,
+          inner_running: false,
+          outside_completed: false,
+        };
+        let inner_unsub = $rc::own(None);
+        let outside = $outside {
+          data: $rc::own(Some(data)),
+          inner_unsub: inner_unsub.clone(),
+          _hint: TypeHint::new(),
+        };
+        let unsub = self.source.actual_subscribe(outside);
+        ZipSubscription::new(inner_unsub, unsub)
+      }
+    }
+
+    impl<$($lf,)? ObservableItem, Item, Err, S> ObservableExt<Item, Err>
+      for $op
+    where
+      S: ObservableExt<ObservableItem, Err>,
+      ObservableItem: ObservableExt<Item, Err>,
+    {
+    }
+
+    impl<$($lf,)? ObservableItem, Item, Err, O> Observer<ObservableItem, Err>
+      for $outside<$($lf,)? O, Item>
+    where
+      O: Observer<Item, Err>,
+      ObservableItem: Observable<Item, Err, $inner<O>>,
+      ObservableItem::Unsub: $($lf)? $($send + 'static)?,
+    {
+      fn next(&mut self, value: ObservableItem) {
+        let id = {
+          let mut data = self.data.rc_deref_mut();
+          let Some(data) = data.as_mut() else { return };
+          data.active += 1;
+          data.inner_running = true;
+          data.active
+        };
+        let prev = self.inner_unsub.rc_deref_mut().take();
+        if let Some(prev) = prev {
+          prev.unsubscribe();
+        }
+        let unsub = value.actual_subscribe($inner { data: self.data.clone(), id });
+        *self.inner_unsub.rc_deref_mut() = Some(<$box_unsub>::new(unsub));
+      }
+
+      fn error(self, err: Err) {
+        let data = self.data.rc_deref_mut().take();
+        if let Some(data) = data {
+          data.observer.error(err);
+        }
+      }
+
+      fn complete(self) {
+        let mut data = self.data.rc_deref_mut();
+        if let Some(inner) = data.as_mut() {
+          inner.outside_completed = true;
+          if !inner.inner_running {
+            data.take().unwrap().observer.complete();
+          }
+        }
+      }
+
+      fn is_finished(&self) -> bool {
+        self
+          .data
+          .rc_deref()
+          .as_ref()
+          .map_or(true, |data| data.observer.is_finished())
+      }
+    }
+
+    impl<Item, Err, O> Observer<Item, Err> for $inner<O>
+    where
+      O: Observer<Item, Err>,
+    {
+      fn next(&mut self, value: Item) {
+        if let Some(data) = self.data.rc_deref_mut().as_mut() {
+          if data.active == self.id {
+            data.observer.next(value);
+          }
+        }
+      }
+
+      fn error(self, err: Err) {
+        let mut data = self.data.rc_deref_mut();
+        // an error of a switched away inner observable is dropped.
+        if data.as_ref().map_or(false, |d| d.active == self.id) {
+          data.take().unwrap().observer.error(err);
+        }
+      }
+
+      fn complete(self) {
+        let mut data = self.data.rc_deref_mut();
+        if let Some(inner) = data.as_mut() {
+          if inner.active == self.id {
+            inner.inner_running = false;
+            if inner.outside_completed {
+              data.take().unwrap().observer.complete();
+            }
+          }
+        }
+      }
+
+      fn is_finished(&self) -> bool {
+        self
+          .data
+          .rc_deref()
+          .as_ref()
+          .map_or(true, |data| {
+            data.active != self.id || data.observer.is_finished()
+          })
+      }
+    }
+  };
+}
+
+impl_switch_all!(
+  SwitchAllOp<'a, S, ObservableItem>,
+  OutsideObserver,
+  InnerObserver,
+  MutRc,
+  BoxSubscription<'a>,
+  'a
+);
+impl_switch_all!(
+  SwitchAllOpThreads<S, ObservableItem>,
+  OutsideObserverThreads,
+  InnerObserverThreads,
+  MutArc,
+  BoxSubscriptionThreads,
+  ,
+  Send
+);
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::{cell::RefCell, rc::Rc};
+
+  #[test]
+  fn switch_to_latest_inner() {
+    let mut values = vec![];
+    let mut outer = Subject::default();
+    let mut first = Subject::default();
+    let mut second = Subject::default();
+
+    outer.clone().switch_all().subscribe(|v| values.push(v));
+
+    outer.next(first.clone());
+    first.next(1);
+    outer.next(second.clone());
+    first.next(2);
+    second.next(3);
+
+    first.retain();
+    assert!(first.is_empty());
+    drop((outer, first, second));
+    assert_eq!(values, vec![1, 3]);
+  }
+
+  // An inner observable that keeps its observer even after it's unsubscribed,
+  // like a source whose teardown races with an in-flight error.
+  struct Stale<O>(Rc<RefCell<Option<O>>>);
+
+  impl<O> Clone for Stale<O> {
+    fn clone(&self) -> Self {
+      Stale(self.0.clone())
+    }
+  }
+
+  impl<O: Observer<i32, &'static str>> Observable<i32, &'static str, O>
+    for Stale<O>
+  {
+    type Unsub = ();
+
+    fn actual_subscribe(self, observer: O) -> Self::Unsub {
+      *self.0.borrow_mut() = Some(observer);
+    }
+  }
+
+  impl<O> ObservableExt<i32, &'static str> for Stale<O> {}
+
+  #[test]
+  fn ignore_superseded_inner_error() {
+    let mut values = vec![];
+    let mut error = None;
+    let mut outer = Subject::default();
+    let first = Stale(Rc::new(RefCell::new(None)));
+    let second = Stale(Rc::new(RefCell::new(None)));
+
+    outer
+      .clone()
+      .switch_all()
+      .on_error(|e| error = Some(e))
+      .subscribe(|v| values.push(v));
+
+    outer.next(first.clone());
+    outer.next(second.clone());
+    // the first inner was switched away from, its error must not end the
+    // stream.
+    let stale = first.0.borrow_mut().take().unwrap();
+    stale.error("stale");
+    let mut active = second.0.borrow_mut().take().unwrap();
+    active.next(1);
+    active.error("active");
+    drop(outer);
+
+    assert_eq!(values, vec![1]);
+    assert_eq!(error, Some("active"));
+  }
+
+  #[test]
+  fn outer_complete_wait_for_active_inner() {
+    let completed = Rc::new(RefCell::new(false));
+    let c_completed = completed.clone();
+    let mut outer = Subject::<_, ()>::default();
+    let mut inner = Subject::<i32, ()>::default();
+    let c_inner = inner.clone();
+
+    outer
+      .clone()
+      .switch_map(move |_: ()| c_inner.clone())
+      .on_complete(move || *c_completed.borrow_mut() = true)
+      .on_error(|_| {})
+      .subscribe(|_| {});
+
+    outer.next(());
+    outer.clone().complete();
+    inner.next(1);
+    assert!(!*completed.borrow());
+
+    inner.complete();
+    assert!(*completed.borrow());
+  }
+
+  #[test]
+  fn inner_complete_not_complete_open_outer() {
+    let completed = Rc::new(RefCell::new(false));
+    let c_completed = completed.clone();
+    let mut outer = Subject::<_, ()>::default();
+
+    outer
+      .clone()
+      .switch_map(|v: i32| observable::of_result(Ok(v)))
+      .on_complete(move || *c_completed.borrow_mut() = true)
+      .on_error(|_| {})
+      .subscribe(|_| {});
+
+    // every inner completes right away, the outer is still open.
+    outer.next(1);
+    outer.next(2);
+    assert!(!*completed.borrow());
+
+    outer.clone().complete();
+    assert!(*completed.borrow());
+  }
+
+  #[test]
+  fn superseded_inner_complete_not_count() {
+    let completed = Rc::new(RefCell::new(false));
+    let c_completed = completed.clone();
+    let mut outer = Subject::<_, ()>::default();
+    let first = Subject::<i32, ()>::default();
+    let second = Subject::<i32, ()>::default();
+
+    outer
+      .clone()
+      .switch_all()
+      .on_complete(move || *c_completed.borrow_mut() = true)
+      .on_error(|_| {})
+      .subscribe(|_| {});
+
+    outer.next(first.clone());
+    outer.next(second.clone());
+    outer.clone().complete();
+    // the first inner was switched away from, the active one is still running.
+    first.complete();
+    assert!(!*completed.borrow());
+
+    second.complete();
+    assert!(*completed.borrow());
+  }
+
+  #[test]
+  fn outer_complete_without_inner() {
+    let mut completed = false;
+    observable::empty()
+      .switch_map(|v: i32| observable::of(v))
+      .on_complete(|| completed = true)
+      .subscribe(|_| {});
+
+    assert!(completed);
+  }
+
+  #[test]
+  fn switch_map_threads() {
+    use crate::rc::{MutArc, RcDeref, RcDerefMut};
+
+    let values = MutArc::own(vec![]);
+    let c_values = values.clone();
+    let mut outer = SubjectThreads::default();
+    let mut inner = SubjectThreads::default();
+    let c_inner = inner.clone();
+    outer
+      .clone()
+      .switch_map_threads(move |v: i32| {
+        observable::of(v).merge_threads(c_inner.clone())
+      })
+      .subscribe(move |v| c_values.rc_deref_mut().push(v));
+
+    outer.next(1);
+    inner.next(10);
+    outer.next(2);
+    inner.next(20);
+
+    assert_eq!(*values.rc_deref(), vec![1, 10, 2, 20]);
+  }
+}
@@ -17,8 +17,25 @@ pub struct TakeUntilOpThreads<S, N, NotifyItem, NotifyErr> {
   _hint: TypeHint<(NotifyItem, NotifyErr)>,
 }
 
+/// Like [`TakeUntilOp`], but completes when the notifier completes instead of
+/// when it emits.
+#[derive(Clone)]
+pub struct TakeUntilCompleteOp<S, N, NotifyItem, NotifyErr> {
+  source: S,
+  notifier: N,
+  _hint: TypeHint<(NotifyItem, NotifyErr)>,
+}
+
+/// The thread safe version of [`TakeUntilCompleteOp`].
+#[derive(Clone)]
+pub struct TakeUntilCompleteOpThreads<S, N, NotifyItem, NotifyErr> {
+  source: S,
+  notifier: N,
+  _hint: TypeHint<(NotifyItem, NotifyErr)>,
+}
+
 macro_rules! impl_take_until {
-  ($name: ident, $rc: ident) => {
+  ($name: ident, $rc: ident, $notifier_observer: ident) => {
     impl<S, N, NotifyItem, NotifyErr> $name<S, N, NotifyItem, NotifyErr> {
       #[inline]
       pub(crate) fn new(source: S, notifier: N) -> Self {
@@ -38,7 +55,7 @@ macro_rules! impl_take_until {
       N: Observable<
         NotifyItem,
         NotifyErr,
-        TakeUntilNotifierObserver<Item, Err, $rc<Option<O>>>,
+        $notifier_observer<Item, Err, $rc<Option<O>>>,
       >,
     {
       type Unsub = ZipSubscription<S::Unsub, N::Unsub>;
@@ -48,7 +65,7 @@ macro_rules! impl_take_until {
         let main_observer = $rc::own(Some(observer));
 
         let a = self.source.actual_subscribe(main_observer.clone());
-        let notify_observer = TakeUntilNotifierObserver {
+        let notify_observer = $notifier_observer {
           main_observer,
           _hint: TypeHint::default(),
         };
@@ -67,8 +84,18 @@ macro_rules! impl_take_until {
   };
 }
 
-impl_take_until!(TakeUntilOp, MutRc);
-impl_take_until!(TakeUntilOpThreads, MutArc);
+impl_take_until!(TakeUntilOp, MutRc, TakeUntilNotifierObserver);
+impl_take_until!(TakeUntilOpThreads, MutArc, TakeUntilNotifierObserver);
+impl_take_until!(
+  TakeUntilCompleteOp,
+  MutRc,
+  TakeUntilCompleteNotifierObserver
+);
+impl_take_until!(
+  TakeUntilCompleteOpThreads,
+  MutArc,
+  TakeUntilCompleteNotifierObserver
+);
 
 pub struct TakeUntilNotifierObserver<Item, Err, O> {
   // We need access to main observer in order to call `complete` on it as soon
@@ -98,6 +125,33 @@ where
   }
 }
 
+pub struct TakeUntilCompleteNotifierObserver<Item, Err, O> {
+  main_observer: O,
+  _hint: TypeHint<(Item, Err)>,
+}
+
+impl<Item, Err, NotifyItem, NotifyErr, O> Observer<NotifyItem, NotifyErr>
+  for TakeUntilCompleteNotifierObserver<Item, Err, O>
+where
+  O: Observer<Item, Err>,
+{
+  #[inline]
+  fn next(&mut self, _: NotifyItem) {}
+
+  #[inline]
+  fn error(self, _: NotifyErr) {}
+
+  #[inline]
+  fn complete(self) {
+    self.main_observer.complete();
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.main_observer.is_finished()
+  }
+}
+
 #[cfg(test)]
 mod test {
   use crate::{
@@ -184,6 +238,48 @@ mod test {
     assert_eq!(*notifier_completed_count.rc_deref(), 2);
   }
 
+  #[test]
+  fn complete_on_notifier_complete() {
+    let mut values = vec![];
+    let mut completed = 0;
+    {
+      let mut notifier = Subject::<_, ()>::default();
+      let mut source = Subject::default();
+      source
+        .clone()
+        .take_until_complete(notifier.clone())
+        .on_complete(|| completed += 1)
+        .subscribe(|i| values.push(i));
+      source.next(1);
+      // emitting doesn't stop the source, only completing does.
+      notifier.next(());
+      source.next(2);
+      notifier.complete();
+      source.next(3);
+      source.complete();
+    }
+    assert_eq!(values, vec![1, 2]);
+    assert_eq!(completed, 1);
+  }
+
+  #[test]
+  fn take_until_complete_threads() {
+    use crate::rc::MutArc;
+
+    let values = MutArc::own(vec![]);
+    let c_values = values.clone();
+    let mut source = SubjectThreads::default();
+    let notifier = SubjectThreads::<(), ()>::default();
+    source
+      .clone()
+      .take_until_complete_threads(notifier.clone())
+      .subscribe(move |i: i32| c_values.rc_deref_mut().push(i));
+    source.next(1);
+    notifier.complete();
+    source.next(2);
+    assert_eq!(*values.rc_deref(), vec![1]);
+  }
+
   #[test]
   fn bench() {
     do_bench();
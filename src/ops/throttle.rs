@@ -142,17 +142,20 @@ where
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::rc::{MutRc, RcDeref};
+  use crate::{
+    observable::fake_timer::FakeClock,
+    rc::{MutRc, RcDeref},
+  };
 
   #[test]
   fn smoke() {
     let x = MutRc::own(vec![]);
-    let mut pool = FuturesLocalSchedulerPool::new();
-    let scheduler = pool.spawner();
+    let clock = FakeClock::default();
 
     let throttle_subscribe = |edge| {
       let x = x.clone();
-      observable::interval(Duration::from_millis(5), scheduler.clone())
+      clock
+        .interval(Duration::from_millis(5))
         .take(5)
         .throttle(
           |val| -> Duration {
@@ -163,49 +166,73 @@ mod tests {
             }
           },
           edge,
-          scheduler.clone(),
+          clock.clone(),
         )
         .subscribe(move |v| x.rc_deref_mut().push(v))
     };
 
     // tailing throttle
     let sub = throttle_subscribe(ThrottleEdge::tailing());
-    pool.run();
+    clock.advance(Duration::from_millis(100));
     sub.unsubscribe();
     assert_eq!(&*x.rc_deref(), &[1, 3, 4]);
 
     // leading throttle
     x.rc_deref_mut().clear();
     throttle_subscribe(ThrottleEdge::leading());
-    pool.run();
+    clock.advance(Duration::from_millis(100));
     assert_eq!(&*x.rc_deref(), &[0, 2, 4]);
   }
 
   #[test]
   fn smoke_for_throttle_time() {
     let x = MutRc::own(vec![]);
-    let mut pool = FuturesLocalSchedulerPool::new();
-    let scheduler = pool.spawner();
-
+    let clock = FakeClock::default();
     let throttle_time_subscribe = |edge| {
       let x = x.clone();
-      observable::interval(Duration::from_millis(50), scheduler.clone())
+      clock
+        .interval(Duration::from_millis(50))
         .take(5)
-        .throttle_time(Duration::from_millis(115), edge, scheduler)
+        .throttle_time(Duration::from_millis(115), edge, clock.clone())
         .subscribe(move |v| x.rc_deref_mut().push(v));
     };
 
     // tailing throttle
-    (throttle_time_subscribe.clone())(ThrottleEdge::tailing());
-    pool.run();
-
+    throttle_time_subscribe(ThrottleEdge::tailing());
+    clock.advance(Duration::from_millis(251));
     assert_eq!(&*x.rc_deref(), &[2, 4]);
 
     // leading throttle
     x.rc_deref_mut().clear();
     throttle_time_subscribe(ThrottleEdge::leading());
-    pool.run();
-
+    clock.advance(Duration::from_millis(251));
     assert_eq!(&*x.rc_deref(), &[0, 3]);
   }
+
+  #[test]
+  fn throttle_all_edges_step_by_step() {
+    let x = MutRc::own(vec![]);
+    let c_x = x.clone();
+    let clock = FakeClock::default();
+    let mut subject = Subject::default();
+    subject
+      .clone()
+      .throttle_time(
+        Duration::from_millis(100),
+        ThrottleEdge::all(),
+        clock.clone(),
+      )
+      .subscribe(move |v| c_x.rc_deref_mut().push(v));
+
+    subject.next(1);
+    assert_eq!(&*x.rc_deref(), &[1]);
+    clock.advance(Duration::from_millis(50));
+    subject.next(2);
+    subject.next(3);
+    assert_eq!(&*x.rc_deref(), &[1]);
+    clock.advance(Duration::from_millis(51));
+    assert_eq!(&*x.rc_deref(), &[1, 3]);
+    subject.next(4);
+    assert_eq!(&*x.rc_deref(), &[1, 3, 4]);
+  }
 }
@@ -0,0 +1,244 @@
+use crate::{
+  prelude::*,
+  rc::{MutArc, RcDerefMut},
+};
+use std::{collections::HashMap, hash::Hash, time::Duration};
+
+/// Emits the latest value of every key at the end of each window. See
+/// [`ObservableExt::throttle_latest_by`].
+#[derive(Clone)]
+pub struct ThrottleLatestByOp<S, F, SD> {
+  pub(crate) source: S,
+  pub(crate) duration: Duration,
+  pub(crate) key_selector: F,
+  pub(crate) scheduler: SD,
+}
+
+/// The latest values of the window, in the order their key first appeared.
+pub struct LatestByKey<K, Item> {
+  index: HashMap<K, usize>,
+  values: Vec<Item>,
+}
+
+type RcLatest<K, Item> = MutArc<LatestByKey<K, Item>>;
+type FlushTask<O, K, Item> =
+  OnceTask<(MutArc<Option<O>>, RcLatest<K, Item>), NormalReturn<()>>;
+
+impl<Item, Err, O, S, F, K, SD> Observable<Item, Err, O>
+  for ThrottleLatestByOp<S, F, SD>
+where
+  O: Observer<Item, Err>,
+  S: Observable<Item, Err, ThrottleLatestByObserver<O, F, K, Item, SD>>,
+  F: FnMut(&Item) -> K,
+  K: Hash + Eq,
+  SD: Scheduler<FlushTask<O, K, Item>>,
+{
+  type Unsub = S::Unsub;
+
+  fn actual_subscribe(self, observer: O) -> Self::Unsub {
+    let Self {
+      source,
+      duration,
+      key_selector,
+      scheduler,
+    } = self;
+    source.actual_subscribe(ThrottleLatestByObserver {
+      observer: MutArc::own(Some(observer)),
+      duration,
+      key_selector,
+      latest: MutArc::own(LatestByKey {
+        index: HashMap::new(),
+        values: vec![],
+      }),
+      task_handler: TaskHandle::value_handle(NormalReturn::new(())),
+      scheduler,
+    })
+  }
+}
+
+impl<Item, Err, S, F, SD> ObservableExt<Item, Err>
+  for ThrottleLatestByOp<S, F, SD>
+where
+  S: ObservableExt<Item, Err>,
+{
+}
+
+pub struct ThrottleLatestByObserver<O, F, K, Item, SD> {
+  observer: MutArc<Option<O>>,
+  duration: Duration,
+  key_selector: F,
+  latest: RcLatest<K, Item>,
+  task_handler: TaskHandle<NormalReturn<()>>,
+  scheduler: SD,
+}
+
+impl<K, Item> LatestByKey<K, Item> {
+  fn take(&mut self) -> Vec<Item> {
+    self.index.clear();
+    std::mem::take(&mut self.values)
+  }
+}
+
+fn flush<O, K, Item, Err>(
+  observer: &mut MutArc<Option<O>>,
+  latest: &RcLatest<K, Item>,
+) where
+  O: Observer<Item, Err>,
+{
+  let values = latest.rc_deref_mut().take();
+  values.into_iter().for_each(|v| observer.next(v));
+}
+
+fn flush_task<O, K, Item, Err>(
+  (mut observer, latest): (MutArc<Option<O>>, RcLatest<K, Item>),
+) -> NormalReturn<()>
+where
+  O: Observer<Item, Err>,
+{
+  flush(&mut observer, &latest);
+  NormalReturn::new(())
+}
+
+impl<Item, Err, O, F, K, SD> Observer<Item, Err>
+  for ThrottleLatestByObserver<O, F, K, Item, SD>
+where
+  O: Observer<Item, Err>,
+  F: FnMut(&Item) -> K,
+  K: Hash + Eq,
+  SD: Scheduler<FlushTask<O, K, Item>>,
+{
+  fn next(&mut self, value: Item) {
+    let key = (self.key_selector)(&value);
+    {
+      let mut latest = self.latest.rc_deref_mut();
+      let LatestByKey { index, values } = &mut *latest;
+      match index.get(&key) {
+        Some(&i) => values[i] = value,
+        None => {
+          index.insert(key, values.len());
+          values.push(value);
+        }
+      }
+    }
+
+    if self.task_handler.is_closed() {
+      let task =
+        OnceTask::new(flush_task, (self.observer.clone(), self.latest.clone()));
+      self.task_handler = self.scheduler.schedule(task, Some(self.duration));
+    }
+  }
+
+  fn error(self, err: Err) {
+    self.task_handler.unsubscribe();
+    self.observer.error(err);
+  }
+
+  fn complete(mut self) {
+    self.task_handler.unsubscribe();
+    flush(&mut self.observer, &self.latest);
+    self.observer.complete();
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.observer.is_finished()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    observable::fake_timer::FakeClock,
+    prelude::*,
+    rc::{MutRc, RcDeref, RcDerefMut},
+  };
+  use std::time::Duration;
+
+  #[test]
+  fn latest_of_every_key() {
+    let x = MutRc::own(vec![]);
+    let c_x = x.clone();
+    let clock = FakeClock::default();
+    let mut subject = Subject::default();
+    subject
+      .clone()
+      .throttle_latest_by(
+        Duration::from_millis(100),
+        |(id, _): &(u32, i32)| *id,
+        clock.clone(),
+      )
+      .subscribe(move |v| c_x.rc_deref_mut().push(v));
+
+    subject.next((1, 1));
+    subject.next((2, 1));
+    subject.next((1, 2));
+    subject.next((1, 3));
+    assert!(x.rc_deref().is_empty());
+    clock.advance(Duration::from_millis(101));
+    assert_eq!(&*x.rc_deref(), &[(1, 3), (2, 1)]);
+
+    // a new window starts with the next value.
+    subject.next((2, 2));
+    clock.advance(Duration::from_millis(50));
+    subject.next((3, 1));
+    assert_eq!(x.rc_deref().len(), 2);
+    clock.advance(Duration::from_millis(51));
+    assert_eq!(&*x.rc_deref(), &[(1, 3), (2, 1), (2, 2), (3, 1)]);
+  }
+
+  #[test]
+  fn flush_pending_on_complete() {
+    let x = MutRc::own(vec![]);
+    let c_x = x.clone();
+    let clock = FakeClock::default();
+    let completed = MutRc::own(false);
+    let c_completed = completed.clone();
+    let mut subject = Subject::default();
+    subject
+      .clone()
+      .throttle_latest_by(
+        Duration::from_millis(100),
+        |v: &i32| v % 2,
+        clock.clone(),
+      )
+      .on_complete(move || *c_completed.rc_deref_mut() = true)
+      .subscribe(move |v| c_x.rc_deref_mut().push(v));
+
+    subject.next(1);
+    subject.next(2);
+    subject.next(3);
+    subject.clone().complete();
+    assert_eq!(&*x.rc_deref(), &[3, 2]);
+    assert!(*completed.rc_deref());
+
+    // the window is cancelled, nothing emitted again.
+    clock.advance(Duration::from_millis(101));
+    assert_eq!(&*x.rc_deref(), &[3, 2]);
+  }
+
+  #[test]
+  fn drop_pending_on_error() {
+    let x = MutRc::own(vec![]);
+    let c_x = x.clone();
+    let clock = FakeClock::default();
+    let error = MutRc::own(None);
+    let c_error = error.clone();
+    let mut subject = Subject::default();
+    subject
+      .clone()
+      .throttle_latest_by(
+        Duration::from_millis(100),
+        |v: &i32| *v,
+        clock.clone(),
+      )
+      .on_error(move |e| *c_error.rc_deref_mut() = Some(e))
+      .subscribe(move |v| c_x.rc_deref_mut().push(v));
+
+    subject.next(1);
+    subject.clone().error("oops");
+    clock.advance(Duration::from_millis(101));
+
+    assert!(x.rc_deref().is_empty());
+    assert_eq!(*error.rc_deref(), Some("oops"));
+  }
+}
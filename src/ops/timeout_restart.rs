@@ -0,0 +1,435 @@
+use crate::{
+  prelude::*,
+  rc::{MutArc, MutRc, RcDeref, RcDerefMut},
+};
+use std::{rc::Rc, sync::Arc};
+
+/// The error of an operator giving up on a source that ran out of time, tells
+/// apart the timeout from an error emitted by the source. See
+/// [`ObservableExt::timeout_restart`] and
+/// [`ObservableExt::subscribe_blocking_timeout`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeoutOrErr<Err> {
+  /// The source didn't finish within the given duration.
+  Timeout,
+  /// The source emitted an error.
+  Error(Err),
+}
+
+/// Resubscribes to the source when it stays silent for longer than a duration,
+/// at most a number of times. See [`ObservableExt::timeout_restart`].
+#[derive(Clone)]
+pub struct TimeoutRestartOp<'a, S, SD> {
+  source: S,
+  duration: Duration,
+  max_restarts: usize,
+  scheduler: SD,
+  _hint: TypeHint<&'a ()>,
+}
+
+/// The thread safe version of [`TimeoutRestartOp`].
+#[derive(Clone)]
+pub struct TimeoutRestartOpThreads<S, SD> {
+  source: S,
+  duration: Duration,
+  max_restarts: usize,
+  scheduler: SD,
+}
+
+pub struct RestartState<U> {
+  restarts_left: usize,
+  // bumped on every restart, the events of a stale subscription are dropped.
+  generation: usize,
+  source_unsub: Option<U>,
+  timer: Option<TaskHandle<NormalReturn<()>>>,
+}
+
+pub struct TimeoutRestartObserver<'a, O> {
+  observer: MutRc<Option<O>>,
+  state: MutRc<RestartState<BoxSubscription<'a>>>,
+  generation: usize,
+  resubscribe: Rc<dyn Fn(TimeoutRestartObserver<'a, O>) + 'a>,
+  arm_timer: Rc<
+    dyn Fn(TimeoutRestartObserver<'a, O>) -> TaskHandle<NormalReturn<()>> + 'a,
+  >,
+}
+
+pub struct TimeoutRestartObserverThreads<O> {
+  observer: MutArc<Option<O>>,
+  state: MutArc<RestartState<BoxSubscriptionThreads>>,
+  generation: usize,
+  resubscribe: Arc<dyn Fn(TimeoutRestartObserverThreads<O>) + Send + Sync>,
+  arm_timer: Arc<
+    dyn Fn(TimeoutRestartObserverThreads<O>) -> TaskHandle<NormalReturn<()>>
+      + Send
+      + Sync,
+  >,
+}
+
+/// Unsubscribes the running subscription of the source and cancels its
+/// silence timer.
+pub struct TimeoutRestartSubscription<O, State>(O, State);
+
+macro_rules! impl_timeout_restart {
+  ($op: ty, $observer: ident, $rc: ident, $ptr: ident, $box_unsub: ident,
+    $($lf: lifetime)? $(,$send: ident)?) => {
+    impl<$($lf,)? S, SD> $op {
+      #[inline]
+      pub(crate) fn new(
+        source: S,
+        duration: Duration,
+        max_restarts: usize,
+        scheduler: SD,
+      ) -> Self {
+        Self {
+          source,
+          duration,
+          max_restarts,
+          scheduler,
+          $(_hint: TypeHint::<&$lf ()>::default(),)?
+        }
+      }
+    }
+
+    impl<$($lf,)? Item, Err, O, S, SD> Observable<Item, TimeoutOrErr<Err>, O>
+      for $op
+    where
+      O: Observer<Item, TimeoutOrErr<Err>> $(+ $lf)? $(+ $send + 'static)?,
+      S: Observable<Item, Err, $observer<$($lf,)? O>>
+        + Clone $(+ $lf)? $(+ $send + Sync + 'static)?,
+      S::Unsub: $($lf)? $($send + 'static)?,
+      SD: Scheduler<OnceTask<$observer<$($lf,)? O>, NormalReturn<()>>>
+        $(+ $lf)? $(+ $send + Sync + 'static)?,
+    {
+      type Unsub = TimeoutRestartSubscription<
+        $rc<Option<O>>,
+        $rc<RestartState<$box_unsub$(<$lf>)?>>,
+      >;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        let Self { source, duration, max_restarts, scheduler, .. } = self;
+        let observer = $rc::own(Some(observer));
+        let state = $rc::own(RestartState {
+          restarts_left: max_restarts,
+          generation: 0,
+          source_unsub: None,
+          timer: None,
+        });
+
+        let arm_timer = $ptr::new(move |observer: $observer<$($lf,)? O>| {
+          let task = OnceTask::new($observer::timeout_task, observer);
+          scheduler.schedule(task, Some(duration))
+        });
+        let c_state = state.clone();
+        let resubscribe = $ptr::new(move |observer: $observer<$($lf,)? O>| {
+          let generation = observer.generation;
+          observer.restart_timer();
+          let unsub = source.clone().actual_subscribe(observer);
+          let mut state = c_state.rc_deref_mut();
+          if state.generation == generation {
+            state.source_unsub = Some($box_unsub::new(unsub));
+          } else {
+            // timed out or finished during the subscription.
+            drop(state);
+            unsub.unsubscribe();
+          }
+        });
+
+        resubscribe($observer {
+          observer: observer.clone(),
+          state: state.clone(),
+          generation: 0,
+          resubscribe: resubscribe.clone(),
+          arm_timer,
+        });
+        TimeoutRestartSubscription(observer, state)
+      }
+    }
+
+    impl<$($lf,)? Item, Err, S, SD> ObservableExt<Item, TimeoutOrErr<Err>>
+      for $op
+    where
+      S: ObservableExt<Item, Err>,
+    {
+    }
+
+    impl<$($lf,)? O> $observer<$($lf,)? O> {
+      fn is_current(&self) -> bool {
+        self.state.rc_deref().generation == self.generation
+      }
+
+      fn fork(&self, generation: usize) -> Self {
+        Self {
+          observer: self.observer.clone(),
+          state: self.state.clone(),
+          generation,
+          resubscribe: self.resubscribe.clone(),
+          arm_timer: self.arm_timer.clone(),
+        }
+      }
+
+      fn restart_timer(&self) {
+        let timer = (self.arm_timer)(self.fork(self.generation));
+        let prev = {
+          let mut state = self.state.rc_deref_mut();
+          if state.generation == self.generation {
+            state.timer.replace(timer)
+          } else {
+            Some(timer)
+          }
+        };
+        if let Some(prev) = prev {
+          prev.unsubscribe();
+        }
+      }
+
+      // Ends the current generation and cancels its timer. The source is
+      // unsubscribed unless it terminated by itself.
+      fn finish(&self, unsubscribe_source: bool) -> bool {
+        let mut state = self.state.rc_deref_mut();
+        if state.generation != self.generation {
+          return false;
+        }
+        state.generation += 1;
+        let timer = state.timer.take();
+        let source_unsub = state.source_unsub.take();
+        drop(state);
+        if let Some(timer) = timer {
+          timer.unsubscribe();
+        }
+        if let Some(source_unsub) = source_unsub.filter(|_| unsubscribe_source) {
+          source_unsub.unsubscribe();
+        }
+        true
+      }
+
+      fn timeout_task<Item, Err>(self) -> NormalReturn<()>
+      where
+        O: Observer<Item, TimeoutOrErr<Err>>,
+      {
+        {
+          // the timer is running this task, it can't be unsubscribed here.
+          let mut state = self.state.rc_deref_mut();
+          if state.generation == self.generation {
+            state.timer.take();
+          }
+        }
+        if self.observer.is_finished() || !self.finish(true) {
+          return NormalReturn::new(());
+        }
+        let restart = {
+          let mut state = self.state.rc_deref_mut();
+          let restart = state.restarts_left > 0;
+          if restart {
+            state.restarts_left -= 1;
+          }
+          restart
+        };
+        if restart {
+          let generation = self.generation + 1;
+          (self.resubscribe)(self.fork(generation));
+        } else {
+          self.observer.error(TimeoutOrErr::Timeout);
+        }
+        NormalReturn::new(())
+      }
+    }
+
+    impl<$($lf,)? Item, Err, O> Observer<Item, Err> for $observer<$($lf,)? O>
+    where
+      O: Observer<Item, TimeoutOrErr<Err>>,
+    {
+      fn next(&mut self, value: Item) {
+        if self.is_current() {
+          self.observer.next(value);
+          self.restart_timer();
+        }
+      }
+
+      fn error(self, err: Err) {
+        if self.finish(false) {
+          self.observer.error(TimeoutOrErr::Error(err));
+        }
+      }
+
+      fn complete(self) {
+        if self.finish(false) {
+          self.observer.complete();
+        }
+      }
+
+      #[inline]
+      fn is_finished(&self) -> bool {
+        !self.is_current() || self.observer.is_finished()
+      }
+    }
+
+    impl<$($lf,)? O> Subscription
+      for TimeoutRestartSubscription<
+        $rc<Option<O>>,
+        $rc<RestartState<$box_unsub$(<$lf>)?>>,
+      >
+    {
+      fn unsubscribe(self) {
+        let Self(observer, state) = self;
+        observer.rc_deref_mut().take();
+        let mut state = state.rc_deref_mut();
+        state.generation += 1;
+        let timer = state.timer.take();
+        let source_unsub = state.source_unsub.take();
+        drop(state);
+        if let Some(timer) = timer {
+          timer.unsubscribe();
+        }
+        if let Some(source_unsub) = source_unsub {
+          source_unsub.unsubscribe();
+        }
+      }
+
+      #[inline]
+      fn is_closed(&self) -> bool {
+        self.0.rc_deref().is_none()
+      }
+    }
+  };
+}
+
+impl_timeout_restart!(
+  TimeoutRestartOp<'a, S, SD>,
+  TimeoutRestartObserver,
+  MutRc,
+  Rc,
+  BoxSubscription,
+  'a
+);
+impl_timeout_restart!(
+  TimeoutRestartOpThreads<S, SD>,
+  TimeoutRestartObserverThreads,
+  MutArc,
+  Arc,
+  BoxSubscriptionThreads,
+  ,
+  Send
+);
+
+#[cfg(test)]
+mod test {
+  use super::*;
+  use crate::observable::fake_timer::FakeClock;
+  use std::{cell::RefCell, rc::Rc};
+
+  #[test]
+  fn restart_silent_source() {
+    let clock = FakeClock::default();
+    let connections = Rc::new(RefCell::new(0));
+    let values = Rc::new(RefCell::new(vec![]));
+    let mut feed = Subject::<i32, &str>::default();
+
+    let (c_connections, c_feed) = (connections.clone(), feed.clone());
+    let c_values = values.clone();
+    observable::defer(move || {
+      *c_connections.borrow_mut() += 1;
+      c_feed.clone()
+    })
+    .timeout_restart(Duration::from_millis(100), 3, clock.clone())
+    .on_error(|_| {})
+    .subscribe(move |v| c_values.borrow_mut().push(v));
+
+    // values keep the subscription alive.
+    for i in 0..5 {
+      clock.advance(Duration::from_millis(50));
+      feed.next(i);
+    }
+    assert_eq!(*connections.borrow(), 1);
+
+    clock.advance(Duration::from_millis(150));
+    assert_eq!(*connections.borrow(), 2);
+    // the stalled subscription is gone, the value is received once.
+    feed.next(5);
+    assert_eq!(*values.borrow(), vec![0, 1, 2, 3, 4, 5]);
+  }
+
+  #[test]
+  fn timeout_after_restarts_exhausted() {
+    let clock = FakeClock::default();
+    let connections = Rc::new(RefCell::new(0));
+    let error = Rc::new(RefCell::new(None));
+    let feed = Subject::<i32, &str>::default();
+
+    let (c_connections, c_feed) = (connections.clone(), feed.clone());
+    let c_error = error.clone();
+    observable::defer(move || {
+      *c_connections.borrow_mut() += 1;
+      c_feed.clone()
+    })
+    .timeout_restart(Duration::from_millis(100), 2, clock.clone())
+    .on_error(move |e| *c_error.borrow_mut() = Some(e))
+    .subscribe(|_| {});
+
+    clock.advance(Duration::from_millis(250));
+    assert_eq!(*connections.borrow(), 3);
+    assert_eq!(*error.borrow(), None);
+
+    clock.advance(Duration::from_millis(100));
+    assert_eq!(*error.borrow(), Some(TimeoutOrErr::Timeout));
+    clock.advance(Duration::from_millis(500));
+    assert_eq!(*connections.borrow(), 3);
+  }
+
+  #[test]
+  fn propagate_source_error() {
+    let clock = FakeClock::default();
+    let error = Rc::new(RefCell::new(None));
+    let c_error = error.clone();
+    let feed = Subject::<i32, &str>::default();
+
+    feed
+      .clone()
+      .timeout_restart(Duration::from_millis(100), 2, clock.clone())
+      .on_error(move |e| *c_error.borrow_mut() = Some(e))
+      .subscribe(|_| {});
+
+    feed.error("closed");
+    assert_eq!(*error.borrow(), Some(TimeoutOrErr::Error("closed")));
+  }
+
+  #[test]
+  fn unsubscribe_cancel_restart() {
+    let clock = FakeClock::default();
+    let connections = Rc::new(RefCell::new(0));
+    let mut feed = Subject::<i32, &str>::default();
+
+    let (c_connections, c_feed) = (connections.clone(), feed.clone());
+    let subscription = observable::defer(move || {
+      *c_connections.borrow_mut() += 1;
+      c_feed.clone()
+    })
+    .timeout_restart(Duration::from_millis(100), 2, clock.clone())
+    .on_error(|_| {})
+    .subscribe(|_| {});
+
+    subscription.unsubscribe();
+    clock.advance(Duration::from_millis(500));
+    assert_eq!(*connections.borrow(), 1);
+    feed.next(1);
+    feed.retain();
+    assert!(feed.is_empty());
+  }
+
+  #[test]
+  fn timeout_restart_threads() {
+    let pool = FuturesThreadPoolScheduler::new().unwrap();
+    let connections = MutArc::own(0);
+    let c_connections = connections.clone();
+    let feed = SubjectThreads::<i32, ()>::default();
+    let res = observable::defer(move || {
+      *c_connections.rc_deref_mut() += 1;
+      feed.clone()
+    })
+    .timeout_restart_threads(Duration::from_millis(10), 1, pool)
+    .subscribe_blocking_timeout(Duration::from_secs(5));
+
+    assert_eq!(res, Err(TimeoutOrErr::Error(TimeoutOrErr::Timeout)));
+    assert_eq!(*connections.rc_deref(), 2);
+  }
+}
@@ -0,0 +1,225 @@
+use crate::prelude::*;
+use std::collections::VecDeque;
+
+/// Emits a window observable every `stride` items, each window emits the next
+/// `size` items of the source and then completes. See
+/// [`ObservableExt::window_count`].
+///
+/// `Subject` is the subject type used for the windows, it decides if the
+/// windows can be shared across threads.
+#[derive(Clone)]
+pub struct WindowCountOp<Source, Subject> {
+  pub(crate) source: Source,
+  pub(crate) size: usize,
+  pub(crate) stride: usize,
+  _hint: TypeHint<Subject>,
+}
+
+impl<Source, Subject> WindowCountOp<Source, Subject> {
+  #[inline]
+  pub(crate) fn new(source: Source, size: usize, stride: usize) -> Self {
+    assert!(size > 0, "`size` must be greater than zero");
+    assert!(stride > 0, "`stride` must be greater than zero");
+    Self {
+      source,
+      size,
+      stride,
+      _hint: TypeHint::default(),
+    }
+  }
+}
+
+pub struct WindowCountObserver<O, Subject> {
+  observer: O,
+  // the open windows, with the count of items they already emitted.
+  windows: VecDeque<(Subject, usize)>,
+  size: usize,
+  stride: usize,
+  index: usize,
+}
+
+macro_rules! impl_observable_for_window_count {
+  ($ty: ty $(,$lf:lifetime)?) => {
+    impl<$($lf,)? Source, Item, Err, O> Observable<$ty, Err, O>
+      for WindowCountOp<Source, $ty>
+    where
+      O: Observer<$ty, Err>,
+      Source: Observable<Item, Err, WindowCountObserver<O, $ty>>,
+      Item: Clone,
+      Err: Clone,
+    {
+      type Unsub = Source::Unsub;
+
+      fn actual_subscribe(self, observer: O) -> Self::Unsub {
+        self.source.actual_subscribe(WindowCountObserver {
+          observer,
+          windows: VecDeque::new(),
+          size: self.size,
+          stride: self.stride,
+          index: 0,
+        })
+      }
+    }
+
+    impl<$($lf,)? Source, Item, Err> ObservableExt<$ty, Err>
+      for WindowCountOp<Source, $ty>
+    where
+      Source: ObservableExt<Item, Err>,
+    {
+    }
+  };
+}
+
+impl_observable_for_window_count!(Subject<'a, Item, Err>, 'a);
+impl_observable_for_window_count!(SubjectThreads<Item, Err>);
+
+impl<Item, Err, O, Subject> Observer<Item, Err>
+  for WindowCountObserver<O, Subject>
+where
+  O: Observer<Subject, Err>,
+  Subject: Clone + Default + Observer<Item, Err>,
+  Item: Clone,
+  Err: Clone,
+{
+  fn next(&mut self, value: Item) {
+    if self.index.is_multiple_of(self.stride) {
+      let window = Subject::default();
+      self.observer.next(window.clone());
+      self.windows.push_back((window, 0));
+    }
+    self.index += 1;
+
+    for (window, count) in self.windows.iter_mut() {
+      window.next(value.clone());
+      *count += 1;
+    }
+    while self
+      .windows
+      .front()
+      .is_some_and(|(_, count)| *count == self.size)
+    {
+      let (window, _) = self.windows.pop_front().unwrap();
+      window.complete();
+    }
+  }
+
+  fn error(mut self, err: Err) {
+    for (window, _) in self.windows.drain(..) {
+      window.error(err.clone());
+    }
+    self.observer.error(err)
+  }
+
+  fn complete(mut self) {
+    for (window, _) in self.windows.drain(..) {
+      window.complete();
+    }
+    self.observer.complete()
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.observer.is_finished()
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::{cell::RefCell, convert::Infallible, rc::Rc};
+
+  #[test]
+  fn sliding_windows() {
+    let mut windows = vec![];
+    observable::from_iter(0..5)
+      .window_count::<Subject<_, _>>(3, 1)
+      .flat_map(|w| w.collect::<Vec<_>>())
+      .subscribe(|w| windows.push(w));
+
+    assert_eq!(
+      windows,
+      vec![
+        vec![0, 1, 2],
+        vec![1, 2, 3],
+        vec![2, 3, 4],
+        vec![3, 4],
+        vec![4]
+      ]
+    );
+  }
+
+  #[test]
+  fn stride_larger_than_size() {
+    let mut windows = vec![];
+    observable::from_iter(0..7)
+      .window_count::<Subject<_, _>>(2, 3)
+      .flat_map(|w| w.collect::<Vec<_>>())
+      .subscribe(|w| windows.push(w));
+
+    assert_eq!(windows, vec![vec![0, 1], vec![3, 4], vec![6]]);
+  }
+
+  #[test]
+  fn window_complete_when_full() {
+    let opened = Rc::new(RefCell::new(0));
+    let completed = Rc::new(RefCell::new(0));
+    let c_opened = opened.clone();
+    let c_completed = completed.clone();
+    let mut source = Subject::<i32, Infallible>::default();
+
+    source
+      .clone()
+      .window_count::<Subject<_, _>>(2, 1)
+      .subscribe(move |w| {
+        *c_opened.borrow_mut() += 1;
+        let completed = c_completed.clone();
+        w.on_complete(move || *completed.borrow_mut() += 1)
+          .subscribe(|_| {});
+      });
+
+    source.next(1);
+    assert_eq!((*opened.borrow(), *completed.borrow()), (1, 0));
+    source.next(2);
+    assert_eq!((*opened.borrow(), *completed.borrow()), (2, 1));
+    source.next(3);
+    assert_eq!((*opened.borrow(), *completed.borrow()), (3, 2));
+  }
+
+  #[test]
+  fn error_all_open_windows() {
+    let errors = Rc::new(RefCell::new(0));
+    let c_errors = errors.clone();
+    let mut source = Subject::<i32, &str>::default();
+
+    source
+      .clone()
+      .window_count::<Subject<_, _>>(3, 1)
+      .on_error(|_| *errors.borrow_mut() += 1)
+      .subscribe(move |w| {
+        let errors = c_errors.clone();
+        w.on_error(move |_| *errors.borrow_mut() += 1)
+          .subscribe(|_| {});
+      });
+
+    source.next(1);
+    source.next(2);
+    source.error("oops");
+
+    // two open windows and the outer observable.
+    assert_eq!(*errors.borrow(), 3);
+  }
+
+  #[test]
+  fn window_count_threads() {
+    use crate::rc::{MutArc, RcDeref, RcDerefMut};
+
+    let sums = MutArc::own(vec![]);
+    let c_sums = sums.clone();
+    observable::from_iter(1..=4)
+      .window_count::<SubjectThreads<_, _>>(2, 2)
+      .flat_map_threads(|w| w.sum())
+      .subscribe(move |v| c_sums.rc_deref_mut().push(v));
+
+    assert_eq!(*sums.rc_deref(), vec![3, 7]);
+  }
+}
@@ -11,7 +11,7 @@ use std::{
   mem::swap,
   panic::{self, AssertUnwindSafe},
   pin::Pin,
-  task::{Context, Poll},
+  task::{Context, Poll, Waker},
 };
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -20,7 +20,7 @@ pub type BoxFuture<'a, T> = futures::future::BoxFuture<'a, T>;
 pub type BoxFuture<'a, T> = futures::future::LocalBoxFuture<'a, T>;
 
 #[cfg(feature = "timer")]
-fn new_timer(dur: Duration) -> BoxFuture<'static, ()> {
+pub(crate) fn new_timer(dur: Duration) -> BoxFuture<'static, ()> {
   #[cfg(not(target_arch = "wasm32"))]
   use futures_time::task::sleep;
   #[cfg(target_arch = "wasm32")]
@@ -35,7 +35,7 @@ pub static NEW_TIMER_FN: once_cell::sync::OnceCell<
 > = once_cell::sync::OnceCell::new();
 
 #[cfg(not(feature = "timer"))]
-fn new_timer(dur: Duration) -> BoxFuture<'static, ()> {
+pub(crate) fn new_timer(dur: Duration) -> BoxFuture<'static, ()> {
   NEW_TIMER_FN
     .get()
     .expect("you can enable the default timer by `timer` feature, or set yourself timer across function `new_timer_fn`")(dur)
@@ -45,6 +45,9 @@ pub struct TaskHandle<T>(MutArc<HandleInfo<T>>);
 struct HandleInfo<T> {
   keep_running: bool,
   value: Option<Result<T, Box<dyn Any + Send>>>,
+  // the waker of the pending task, to let a cancelled task finish and drop
+  // its future right away.
+  waker: Option<Waker>,
 }
 
 pub trait Scheduler<T>: Clone
@@ -53,6 +56,25 @@ where
 {
   fn schedule(&self, task: T, delay: Option<Duration>)
     -> TaskHandle<T::Output>;
+
+  /// The current time of the scheduler, used by the operators that align
+  /// their work to the clock. A virtual clock for testing returns its own
+  /// time.
+  #[inline]
+  fn now(&self) -> Instant {
+    Instant::now()
+  }
+
+  /// The origin of the clock of the scheduler, the aligned operators tick at
+  /// the multiples of their period since it. The schedulers on the real clock
+  /// share the origin of the process, a virtual clock for testing returns the
+  /// time it started at.
+  #[inline]
+  fn origin(&self) -> Instant {
+    static ORIGIN: once_cell::sync::Lazy<Instant> =
+      once_cell::sync::Lazy::new(Instant::now);
+    *ORIGIN
+  }
 }
 
 pin_project! {
@@ -184,8 +206,15 @@ impl<T> TaskHandle<T> {
     Self(MutArc::own(HandleInfo {
       keep_running: true,
       value: Some(Ok(v)),
+      waker: None,
     }))
   }
+
+  fn wake_cancelled(waker: Option<Waker>) {
+    if let Some(waker) = waker {
+      waker.wake();
+    }
+  }
 }
 trait TaskReturn {}
 
@@ -195,9 +224,13 @@ impl<T> TaskReturn for NormalReturn<T> {}
 impl<T: 'static> Subscription for TaskHandle<NormalReturn<T>> {
   #[inline]
   fn unsubscribe(self) {
-    let mut inner = self.0.rc_deref_mut();
-    inner.keep_running = false;
-    inner.value.take();
+    let waker = {
+      let mut inner = self.0.rc_deref_mut();
+      inner.keep_running = false;
+      inner.value.take();
+      inner.waker.take()
+    };
+    Self::wake_cancelled(waker);
   }
 
   #[inline]
@@ -210,9 +243,13 @@ impl<T: Subscription + 'static> Subscription
   for TaskHandle<SubscribeReturn<T>>
 {
   fn unsubscribe(self) {
-    let mut info = self.0.rc_deref_mut();
-    info.keep_running = false;
-    match info.value.take() {
+    let (value, waker) = {
+      let mut info = self.0.rc_deref_mut();
+      info.keep_running = false;
+      (info.value.take(), info.waker.take())
+    };
+    Self::wake_cancelled(waker);
+    match value {
       Some(Ok(v)) => v.0.unsubscribe(),
       Some(Err(e)) => panic::resume_unwind(e),
       None => {}
@@ -257,17 +294,30 @@ impl<Fut: Future> Future for Remote<Fut> {
       // Cancelled, bail out
       return Poll::Ready(());
     }
-    info.value = Some(ready!(this.future.poll(cx)));
-
-    Poll::Ready(())
+    match this.future.poll(cx) {
+      Poll::Ready(value) => {
+        info.value = Some(value);
+        info.waker = None;
+        Poll::Ready(())
+      }
+      Poll::Pending => {
+        if !info.waker.as_ref().is_some_and(|w| w.will_wake(cx.waker())) {
+          info.waker = Some(cx.waker().clone());
+        }
+        Poll::Pending
+      }
+    }
   }
 }
 
 fn remote_handle<Fut: Future>(
   future: Fut,
 ) -> (Remote<Fut>, TaskHandle<Fut::Output>) {
-  let handle =
-    TaskHandle(MutArc::own(HandleInfo { keep_running: true, value: None }));
+  let handle = TaskHandle(MutArc::own(HandleInfo {
+    keep_running: true,
+    value: None,
+    waker: None,
+  }));
 
   // Unwind Safety: See the docs for RemoteHandle.
   let wrapped = Remote {
@@ -278,6 +328,20 @@ fn remote_handle<Fut: Future>(
   (wrapped, handle)
 }
 
+/// Wraps a local task with the handle controlling it, for a scheduler that
+/// polls the task by itself, like
+/// [`FakeClock`](crate::observable::fake_timer::FakeClock).
+#[cfg(test)]
+pub(crate) fn local_remote_handle<Fut: Future + 'static>(
+  future: Fut,
+) -> (
+  futures::future::LocalBoxFuture<'static, ()>,
+  TaskHandle<Fut::Output>,
+) {
+  let (wrapped, handle) = remote_handle(future);
+  (wrapped.boxed_local(), handle)
+}
+
 #[cfg(target_arch = "wasm32")]
 #[derive(Debug, Clone)]
 pub struct WasmLocalScheduler;
@@ -297,9 +361,12 @@ macro_rules! impl_scheduler_method {
       task: T,
       delay: Option<Duration>,
     ) -> TaskHandle<T::Output> {
+      // start the timer now rather than at the first poll, so a busy
+      // executor doesn't push back the deadline.
+      let timer = delay.map(new_timer);
       let fut = async move {
-        if let Some(dur) = delay {
-          new_timer(dur).await;
+        if let Some(timer) = timer {
+          timer.await;
         }
         task.await
       };
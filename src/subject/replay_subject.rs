@@ -0,0 +1,274 @@
+use crate::prelude::*;
+use crate::rc::{AssociatedRefPtr, RcDeref, RcDerefMut};
+use std::collections::VecDeque;
+
+/// A subject that replays the last `buffer_size` values to every new
+/// subscriber before the values emitted after its subscription.
+///
+/// A subscriber arriving after the subject terminated receives the buffered
+/// values, followed by the completion or the error of the subject.
+#[derive(Clone)]
+pub struct ReplaySubject<Item, Err, Subject: AssociatedRefPtr> {
+  pub(crate) subject: Subject,
+  pub(crate) buffer: Subject::Rc<VecDeque<Item>>,
+  pub(crate) buffer_size: usize,
+  pub(crate) termination: Subject::Rc<Option<ReplayTermination<Err>>>,
+}
+
+/// How a [`ReplaySubject`] terminated, replayed to the late subscribers.
+#[derive(Clone)]
+pub enum ReplayTermination<Err> {
+  Complete,
+  Error(Err),
+}
+
+/// The subscription of a [`ReplaySubject`], a subscriber arriving after the
+/// subject terminated has nothing to unsubscribe.
+pub struct ReplaySubscription<U>(Option<U>);
+
+impl<Item, Err, Subject: Default + AssociatedRefPtr>
+  ReplaySubject<Item, Err, Subject>
+{
+  pub fn new(buffer_size: usize) -> Self {
+    Self {
+      subject: <_>::default(),
+      buffer: VecDeque::with_capacity(buffer_size).into(),
+      buffer_size,
+      termination: None.into(),
+    }
+  }
+}
+
+impl<Item: Clone, Err, Subject: AssociatedRefPtr>
+  ReplaySubject<Item, Err, Subject>
+{
+  /// Returns a clone of the values in the replay buffer, oldest first, to
+  /// peek at the history without subscribing.
+  pub fn snapshot(&self) -> Vec<Item> {
+    self.buffer.rc_deref().iter().cloned().collect()
+  }
+}
+
+impl<Item, Err, Subject: AssociatedRefPtr> Observer<Item, Err>
+  for ReplaySubject<Item, Err, Subject>
+where
+  Subject: Observer<Item, Err>,
+  Item: Clone,
+  Err: Clone,
+{
+  fn next(&mut self, value: Item) {
+    if self.buffer_size > 0 {
+      let mut buffer = self.buffer.rc_deref_mut();
+      if buffer.len() == self.buffer_size {
+        buffer.pop_front();
+      }
+      buffer.push_back(value.clone());
+    }
+    Observer::next(&mut self.subject, value);
+  }
+
+  fn error(self, err: Err) {
+    *self.termination.rc_deref_mut() =
+      Some(ReplayTermination::Error(err.clone()));
+    self.subject.error(err)
+  }
+
+  fn complete(self) {
+    *self.termination.rc_deref_mut() = Some(ReplayTermination::Complete);
+    self.subject.complete()
+  }
+
+  #[inline]
+  fn is_finished(&self) -> bool {
+    self.subject.is_finished()
+  }
+}
+
+impl<Item, Err, Subject: AssociatedRefPtr> Subscription
+  for ReplaySubject<Item, Err, Subject>
+where
+  Subject: Subscription,
+{
+  #[inline]
+  fn unsubscribe(self) {
+    self.subject.unsubscribe();
+  }
+
+  #[inline]
+  fn is_closed(&self) -> bool {
+    self.subject.is_closed()
+  }
+}
+
+impl<Item, Err, Subject: AssociatedRefPtr> SubjectSize
+  for ReplaySubject<Item, Err, Subject>
+where
+  Subject: SubjectSize,
+{
+  #[inline]
+  fn is_empty(&self) -> bool {
+    self.subject.is_empty()
+  }
+
+  #[inline]
+  fn len(&self) -> usize {
+    self.subject.len()
+  }
+}
+
+impl<Item, Err, O, Subject: AssociatedRefPtr> Observable<Item, Err, O>
+  for ReplaySubject<Item, Err, Subject>
+where
+  Subject: Observable<Item, Err, O>,
+  O: Observer<Item, Err>,
+  Item: Clone,
+  Err: Clone,
+{
+  type Unsub = ReplaySubscription<Subject::Unsub>;
+
+  fn actual_subscribe(self, mut observer: O) -> Self::Unsub {
+    // replay a snapshot, so the observer may emit to the subject meanwhile.
+    for value in self.snapshot() {
+      observer.next(value);
+    }
+    let termination = self.termination.rc_deref().clone();
+    match termination {
+      Some(ReplayTermination::Complete) => observer.complete(),
+      Some(ReplayTermination::Error(err)) => observer.error(err),
+      None => {
+        return ReplaySubscription(Some(
+          self.subject.actual_subscribe(observer),
+        ))
+      }
+    }
+    ReplaySubscription(None)
+  }
+}
+
+impl<Item, Err, Subject: AssociatedRefPtr> ObservableExt<Item, Err>
+  for ReplaySubject<Item, Err, Subject>
+where
+  Subject: ObservableExt<Item, Err>,
+{
+}
+
+impl<U: Subscription> Subscription for ReplaySubscription<U> {
+  #[inline]
+  fn unsubscribe(self) {
+    if let Some(u) = self.0 {
+      u.unsubscribe();
+    }
+  }
+
+  #[inline]
+  fn is_closed(&self) -> bool {
+    self.0.as_ref().is_none_or(|u| u.is_closed())
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use crate::prelude::*;
+  use std::convert::Infallible;
+
+  #[test]
+  fn replay_last_values() {
+    let mut values = vec![];
+    {
+      let mut subject = ReplaySubject::<_, _, Subject<_, _>>::new(2);
+      subject.next(1);
+      subject.next(2);
+      subject.next(3);
+
+      subject.clone().subscribe(|v| values.push(v));
+      subject.next(4);
+    }
+
+    assert_eq!(values, vec![2, 3, 4]);
+  }
+
+  #[test]
+  fn snapshot() {
+    let mut subject = ReplaySubject::<_, _, Subject<_, Infallible>>::new(3);
+    assert!(subject.snapshot().is_empty());
+
+    for v in 1..=4 {
+      subject.next(v);
+    }
+
+    assert_eq!(subject.snapshot(), vec![2, 3, 4]);
+    // peeking doesn't consume the buffer.
+    assert_eq!(subject.clone().snapshot(), vec![2, 3, 4]);
+  }
+
+  #[test]
+  fn zero_buffer_size() {
+    let mut values = vec![];
+    {
+      let mut subject = ReplaySubject::<_, _, Subject<_, _>>::new(0);
+      subject.next(1);
+
+      subject.clone().subscribe(|v| values.push(v));
+      subject.next(2);
+      assert!(subject.snapshot().is_empty());
+    }
+
+    assert_eq!(values, vec![2]);
+  }
+
+  #[test]
+  fn replay_threads() {
+    let mut subject = ReplaySubject::<_, _, SubjectThreads<_, _>>::new(2);
+    subject.next(1);
+    subject.next(2);
+
+    let values = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+    let c_values = values.clone();
+    subject
+      .clone()
+      .subscribe(move |v| c_values.lock().unwrap().push(v));
+    subject.next(3);
+
+    assert_eq!(*values.lock().unwrap(), vec![1, 2, 3]);
+    assert_eq!(subject.snapshot(), vec![2, 3]);
+  }
+
+  #[test]
+  fn replay_complete_to_late_subscriber() {
+    let mut values = vec![];
+    let mut completed = false;
+    {
+      let mut subject = ReplaySubject::<_, _, Subject<_, Infallible>>::new(2);
+      subject.next(1);
+      subject.next(2);
+      subject.clone().complete();
+
+      let subscription = subject
+        .on_complete(|| completed = true)
+        .subscribe(|v| values.push(v));
+      assert!(subscription.is_closed());
+    }
+
+    assert_eq!(values, vec![1, 2]);
+    assert!(completed);
+  }
+
+  #[test]
+  fn replay_error_to_late_subscriber() {
+    let mut values = vec![];
+    let mut error = None;
+    {
+      let mut subject = ReplaySubject::<_, _, Subject<_, _>>::new(1);
+      subject.next(1);
+      subject.next(2);
+      subject.clone().error("oops");
+
+      subject
+        .on_error(|e| error = Some(e))
+        .subscribe(|v| values.push(v));
+    }
+
+    assert_eq!(values, vec![2]);
+    assert_eq!(error, Some("oops"));
+  }
+}
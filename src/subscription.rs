@@ -111,6 +111,19 @@ macro_rules! impl_multi_subscription {
           vec.retain(|v| v.is_some());
         }
       }
+
+      /// Returns the number of live child subscriptions, the closed ones are
+      /// dropped first. Helps to find children that are never released.
+      ///
+      /// Don't call it from inside a task scheduled by one of the children,
+      /// checking if that task is closed would wait for the task itself.
+      pub fn child_count(&self) -> usize {
+        let mut inner = self.0.rc_deref_mut();
+        inner.as_mut().map_or(0, |vec| {
+          vec.retain(|v| v.as_ref().is_some_and(|u| !u.is_closed()));
+          vec.len()
+        })
+      }
     }
   };
 }
@@ -260,6 +273,49 @@ mod test {
     assert_eq!(shared.teardown_size(), 3);
   }
 
+  #[test]
+  fn child_count_prune_closed() {
+    use crate::prelude::*;
+
+    let subject = Subject::<i32, std::convert::Infallible>::default();
+    let mut local = MultiSubscription::default();
+    let s1 = subject.clone().subscribe(|_| {});
+    local.append(BoxSubscription::new(s1.clone()));
+    local.append(BoxSubscription::new(subject.clone().subscribe(|_| {})));
+    local.append(BoxSubscription::new(()));
+    assert_eq!(local.teardown_size(), 3);
+    assert_eq!(local.child_count(), 2);
+
+    s1.unsubscribe();
+    assert_eq!(local.child_count(), 1);
+    local.clone().unsubscribe();
+    assert_eq!(local.child_count(), 0);
+  }
+
+  #[test]
+  fn child_count_after_flat_map() {
+    use crate::prelude::*;
+
+    let mut outer = Subject::default();
+    let inner1 = Subject::<i32, ()>::default();
+    let inner2 = Subject::<i32, ()>::default();
+    let subscription = outer
+      .clone()
+      .flat_map(|s: Subject<i32, ()>| s)
+      .on_error(|_| {})
+      .subscribe(|_| {});
+
+    outer.next(inner1.clone());
+    outer.next(inner2.clone());
+    // the outer subject and two inner subjects.
+    assert_eq!(subscription.child_count(), 3);
+    inner1.complete();
+    assert_eq!(subscription.child_count(), 2);
+
+    subscription.clone().unsubscribe();
+    assert_eq!(subscription.child_count(), 0);
+  }
+
   #[test]
   fn fix_box_subscription_no_proxy() {
     let a = BoxSubscription::new(());